@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use win_msgbox::{testing, MessageBox, Okay};
+use windows_sys::Win32::UI::WindowsAndMessaging::IDOK;
+
+/// Shows message boxes with canned responses, so only the overhead of `win_msgbox` is measured.
+fn show(c: &mut Criterion) {
+    let text = "Some longer text to encode.\n".repeat(32);
+    let msgbox = MessageBox::<Okay>::new(text.as_str()).title("Benchmark");
+
+    c.bench_function("show_ref", |b| {
+        b.iter(|| {
+            testing::push_response(IDOK);
+            black_box(msgbox.show_ref())
+        })
+    });
+
+    let short = MessageBox::<Okay>::new("Done");
+    c.bench_function("show_ref (short)", |b| {
+        b.iter(|| {
+            testing::push_response(IDOK);
+            black_box(short.show_ref())
+        })
+    });
+
+    let mut buf = Vec::new();
+    c.bench_function("show_with_buffer", |b| {
+        b.iter(|| {
+            testing::push_response(IDOK);
+            black_box(msgbox.show_with_buffer(&mut buf))
+        })
+    });
+}
+
+criterion_group!(benches, show);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+use win_msgbox::{raw::WideString, CustomIcon, Okay, Result};
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    DestroyIcon, LoadImageW, IMAGE_ICON, LR_DEFAULTSIZE, LR_LOADFROMFILE,
+};
+
+fn main() -> Result<()> {
+    let path = WideString::from(std::env::args().nth(1).as_deref().unwrap_or("app.ico"));
+    let icon = unsafe {
+        LoadImageW(
+            std::ptr::null_mut(),
+            path.as_ptr(),
+            IMAGE_ICON,
+            0,
+            0,
+            LR_LOADFROMFILE | LR_DEFAULTSIZE,
+        )
+    };
+
+    let mut msgbox = win_msgbox::information::<Okay>("The update was installed.").title("Updater");
+    if !icon.is_null() {
+        msgbox = msgbox.icon_handle(CustomIcon(icon));
+    }
+    msgbox.show()?;
+
+    if !icon.is_null() {
+        unsafe { DestroyIcon(icon) };
+    }
+    Ok(())
+}
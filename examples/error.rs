@@ -0,0 +1,9 @@
+use std::fs::File;
+use win_msgbox::{MessageBox, Result};
+
+fn main() -> Result<()> {
+    if let Err(e) = File::open("does-not-exist.txt") {
+        MessageBox::from_error(&e).show()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use win_msgbox::{MessageBox, Result, RetryCancel};
+
+fn main() -> Result<()> {
+    let mut attempts = 0;
+    MessageBox::<RetryCancel>::error("Couldn't connect to the server")
+        .title("Connection Error")
+        .show_while(|response| {
+            attempts += 1;
+            *response == RetryCancel::Retry
+        })?;
+    println!("Gave up after {attempts} attempts");
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use win_msgbox::{Okay, Result, Sound};
+
+fn main() -> Result<()> {
+    win_msgbox::warning::<Okay>("The disk is almost full.")
+        .title("Custom Alert")
+        .silent()
+        .with_sound(Sound::Custom(r"C:\Windows\Media\Windows Critical Stop.wav"))
+        .show()?;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use win_msgbox::{Okay, Result};
+
+fn main() -> Result<()> {
+    // A tray app has no window to own the message box,
+    // so it's shown where the user is working instead of on the primary monitor.
+    win_msgbox::information::<Okay>("Your download has finished.")
+        .title("Downloader")
+        .center_on_screen()
+        .no_steal_focus()
+        .show()?;
+    Ok(())
+}
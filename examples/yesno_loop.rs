@@ -0,0 +1,15 @@
+use win_msgbox::Result;
+
+fn main() -> Result<()> {
+    let mut count = 0;
+    loop {
+        count += 1;
+        if !win_msgbox::confirm_with_title(
+            &format!("You've seen this {count} time(s). Show it again?"),
+            "Loop",
+        )? {
+            break;
+        }
+    }
+    Ok(())
+}
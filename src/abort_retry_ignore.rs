@@ -1,10 +1,12 @@
-use super::Options;
+use super::{DefaultButton, Options, RetryOrSkip, UnknownResult};
+use std::ops::ControlFlow;
 use windows_sys::Win32::UI::WindowsAndMessaging::{
-    IDABORT, IDRETRY, MB_ABORTRETRYIGNORE, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDABORT, IDIGNORE, IDRETRY, MB_ABORTRETRYIGNORE, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// The message box contains three push buttons: **Abort**, **Retry**, and **Ignore**.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AbortRetryIgnore {
     /// The **Abort** button was selected.
     Abort,
@@ -14,6 +16,54 @@ pub enum AbortRetryIgnore {
     Ignore,
 }
 
+impl AbortRetryIgnore {
+    /// Returns `true` if the **Abort** button was selected.
+    ///
+    /// This message box has no **Cancel** button, so pressing ESC has no effect.
+    ///
+    /// ```
+    /// use win_msgbox::AbortRetryIgnore;
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::IDABORT;
+    ///
+    /// assert!(AbortRetryIgnore::from(IDABORT).aborted());
+    /// ```
+    pub fn aborted(self) -> bool {
+        self == Self::Abort
+    }
+
+    /// Converts the selection into a control-flow decision:
+    /// **Abort** breaks, **Retry** and **Ignore** continue with [`Retry`](RetryOrSkip::Retry) and [`Skip`](RetryOrSkip::Skip).
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    /// use win_msgbox::{AbortRetryIgnore, RetryOrSkip};
+    ///
+    /// assert_eq!(AbortRetryIgnore::Abort.control_flow(), ControlFlow::Break(()));
+    /// assert_eq!(AbortRetryIgnore::Retry.control_flow(), ControlFlow::Continue(RetryOrSkip::Retry));
+    /// assert_eq!(AbortRetryIgnore::Ignore.control_flow(), ControlFlow::Continue(RetryOrSkip::Skip));
+    /// ```
+    pub fn control_flow(self) -> ControlFlow<(), RetryOrSkip> {
+        match self {
+            Self::Abort => ControlFlow::Break(()),
+            Self::Retry => ControlFlow::Continue(RetryOrSkip::Retry),
+            Self::Ignore => ControlFlow::Continue(RetryOrSkip::Skip),
+        }
+    }
+
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDABORT => Ok(Self::Abort),
+            IDRETRY => Ok(Self::Retry),
+            IDIGNORE => Ok(Self::Ignore),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// Unknown codes are mapped to [`Ignore`](AbortRetryIgnore::Ignore). Use [`try_from_code`](Self::try_from_code) to reject them.
 impl From<MESSAGEBOX_RESULT> for AbortRetryIgnore {
     fn from(value: MESSAGEBOX_RESULT) -> Self {
         match value {
@@ -24,8 +74,42 @@ impl From<MESSAGEBOX_RESULT> for AbortRetryIgnore {
     }
 }
 
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::AbortRetryIgnore;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDABORT, IDIGNORE};
+///
+/// assert!(AbortRetryIgnore::Ignore == IDIGNORE);
+/// assert!(AbortRetryIgnore::Ignore != IDABORT);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for AbortRetryIgnore {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        let code = match self {
+            Self::Abort => IDABORT,
+            Self::Retry => IDRETRY,
+            Self::Ignore => IDIGNORE,
+        };
+        *other == code
+    }
+}
+
 impl Options for AbortRetryIgnore {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_ABORTRETRYIGNORE
+    const FLAGS: MESSAGEBOX_STYLE = MB_ABORTRETRYIGNORE;
+    const CAPTIONS: &'static [&'static str] = &["Abort", "Retry", "Ignore"];
+
+    fn safe_button() -> DefaultButton {
+        // Abort
+        DefaultButton::DefaultButton1
+    }
+}
+
+impl std::fmt::Display for AbortRetryIgnore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Abort => "Abort",
+            Self::Retry => "Retry",
+            Self::Ignore => "Ignore",
+        })
     }
 }
@@ -1,11 +1,13 @@
-use super::Options;
+use super::{DefaultButton, Options, RetryOrSkip, UnknownResult};
+use std::ops::ControlFlow;
 use windows_sys::Win32::UI::WindowsAndMessaging::{
-    IDCONTINUE, IDTRYAGAIN, MB_CANCELTRYCONTINUE, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDCANCEL, IDCONTINUE, IDTRYAGAIN, MB_CANCELTRYCONTINUE, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// The message box contains three push buttons: **Cancel**, **Try Again**, **Continue**.
 /// Use this message box type instead of [AbortRetryIgnore](crate::AbortRetryIgnore).
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CancelTryAgainContinue {
     /// The **Cancel** button was selected.
     Cancel,
@@ -15,6 +17,60 @@ pub enum CancelTryAgainContinue {
     Continue,
 }
 
+impl CancelTryAgainContinue {
+    /// Returns `true` if the **Cancel** button was selected or ESC was pressed.
+    ///
+    /// Pressing ESC in this message box returns [`Cancel`](Self::Cancel).
+    ///
+    /// ```
+    /// use win_msgbox::CancelTryAgainContinue;
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::IDCANCEL;
+    ///
+    /// assert!(CancelTryAgainContinue::from(IDCANCEL).cancelled());
+    /// ```
+    pub fn cancelled(self) -> bool {
+        self == Self::Cancel
+    }
+
+    /// Converts the selection into a control-flow decision:
+    /// **Cancel** breaks, **Try Again** and **Continue** continue with [`Retry`](RetryOrSkip::Retry) and [`Skip`](RetryOrSkip::Skip).
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    /// use win_msgbox::{CancelTryAgainContinue, RetryOrSkip};
+    ///
+    /// assert_eq!(CancelTryAgainContinue::Cancel.control_flow(), ControlFlow::Break(()));
+    /// assert_eq!(
+    ///     CancelTryAgainContinue::TryAgain.control_flow(),
+    ///     ControlFlow::Continue(RetryOrSkip::Retry)
+    /// );
+    /// assert_eq!(
+    ///     CancelTryAgainContinue::Continue.control_flow(),
+    ///     ControlFlow::Continue(RetryOrSkip::Skip)
+    /// );
+    /// ```
+    pub fn control_flow(self) -> ControlFlow<(), RetryOrSkip> {
+        match self {
+            Self::Cancel => ControlFlow::Break(()),
+            Self::TryAgain => ControlFlow::Continue(RetryOrSkip::Retry),
+            Self::Continue => ControlFlow::Continue(RetryOrSkip::Skip),
+        }
+    }
+
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDCANCEL => Ok(Self::Cancel),
+            IDTRYAGAIN => Ok(Self::TryAgain),
+            IDCONTINUE => Ok(Self::Continue),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// Unknown codes are mapped to [`Cancel`](CancelTryAgainContinue::Cancel). Use [`try_from_code`](Self::try_from_code) to reject them.
 impl From<MESSAGEBOX_RESULT> for CancelTryAgainContinue {
     fn from(value: MESSAGEBOX_RESULT) -> Self {
         match value {
@@ -25,8 +81,42 @@ impl From<MESSAGEBOX_RESULT> for CancelTryAgainContinue {
     }
 }
 
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::CancelTryAgainContinue;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDCONTINUE};
+///
+/// assert!(CancelTryAgainContinue::Continue == IDCONTINUE);
+/// assert!(CancelTryAgainContinue::Continue != IDCANCEL);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for CancelTryAgainContinue {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        let code = match self {
+            Self::Cancel => IDCANCEL,
+            Self::TryAgain => IDTRYAGAIN,
+            Self::Continue => IDCONTINUE,
+        };
+        *other == code
+    }
+}
+
 impl Options for CancelTryAgainContinue {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_CANCELTRYCONTINUE
+    const FLAGS: MESSAGEBOX_STYLE = MB_CANCELTRYCONTINUE;
+    const CAPTIONS: &'static [&'static str] = &["Cancel", "Try Again", "Continue"];
+
+    fn safe_button() -> DefaultButton {
+        // Cancel
+        DefaultButton::DefaultButton1
+    }
+}
+
+impl std::fmt::Display for CancelTryAgainContinue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Cancel => "Cancel",
+            Self::TryAgain => "Try Again",
+            Self::Continue => "Continue",
+        })
     }
 }
@@ -0,0 +1,96 @@
+use crate::{MsgBoxError, Result};
+use std::{fs, path::Path};
+use windows_sys::Win32::{
+    Foundation::{GetLastError, ERROR_INVALID_PARAMETER, ERROR_WRITE_FAULT, HWND, RECT},
+    Graphics::Gdi::{
+        CreateCompatibleBitmap, CreateCompatibleDC, DeleteDC, DeleteObject, GetDC, GetDIBits,
+        ReleaseDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    },
+    Storage::Xps::PrintWindow,
+    UI::WindowsAndMessaging::{GetWindowRect, PW_RENDERFULLCONTENT},
+};
+
+/// The size of the `BITMAPFILEHEADER` preceding the `BITMAPINFOHEADER` in a `.bmp` file.
+const FILE_HEADER_LEN: u32 = 14;
+
+/// Renders `hwnd` into a 32-bit bitmap and writes it to `path` as a `.bmp` file.
+pub(crate) fn capture_window(hwnd: HWND, path: &Path) -> Result<()> {
+    let bitmap = render_window(hwnd)?;
+    fs::write(path, bitmap).map_err(|e| {
+        MsgBoxError::Win32(
+            e.raw_os_error()
+                .map_or(ERROR_WRITE_FAULT, |code| code as u32),
+        )
+    })
+}
+
+/// Renders `hwnd` and returns the contents of a `.bmp` file.
+fn render_window(hwnd: HWND) -> Result<Vec<u8>> {
+    let mut rect: RECT = unsafe { std::mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut rect) } == 0 {
+        return Err(unsafe { GetLastError() }.into());
+    }
+    let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+    if width <= 0 || height <= 0 {
+        return Err(ERROR_INVALID_PARAMETER.into());
+    }
+
+    let mut header: BITMAPINFOHEADER = unsafe { std::mem::zeroed() };
+    header.biSize = std::mem::size_of::<BITMAPINFOHEADER>() as u32;
+    header.biWidth = width;
+    // A negative height stores the rows top-down.
+    header.biHeight = -height;
+    header.biPlanes = 1;
+    header.biBitCount = 32;
+    header.biCompression = BI_RGB;
+    header.biSizeImage = width as u32 * height as u32 * 4;
+    let mut info = BITMAPINFO {
+        bmiHeader: header,
+        bmiColors: unsafe { std::mem::zeroed() },
+    };
+    let mut pixels = vec![0u8; header.biSizeImage as usize];
+
+    let (copied, error) = unsafe {
+        let screen = GetDC(std::ptr::null_mut());
+        let dc = CreateCompatibleDC(screen);
+        let bitmap = CreateCompatibleBitmap(screen, width, height);
+        let previous = SelectObject(dc, bitmap);
+        let copied = PrintWindow(hwnd, dc, PW_RENDERFULLCONTENT) != 0
+            && GetDIBits(
+                dc,
+                bitmap,
+                0,
+                height as u32,
+                pixels.as_mut_ptr().cast(),
+                &mut info,
+                DIB_RGB_COLORS,
+            ) == height;
+        let error = GetLastError();
+        SelectObject(dc, previous);
+        DeleteObject(bitmap);
+        DeleteDC(dc);
+        ReleaseDC(std::ptr::null_mut(), screen);
+        (copied, error)
+    };
+    if !copied {
+        return Err(error.into());
+    }
+
+    let offset = FILE_HEADER_LEN + header.biSize;
+    let mut file = Vec::with_capacity(offset as usize + pixels.len());
+    file.extend_from_slice(b"BM");
+    file.extend_from_slice(&(offset + header.biSizeImage).to_le_bytes());
+    file.extend_from_slice(&[0; 4]);
+    file.extend_from_slice(&offset.to_le_bytes());
+    file.extend_from_slice(&header.biSize.to_le_bytes());
+    file.extend_from_slice(&header.biWidth.to_le_bytes());
+    file.extend_from_slice(&header.biHeight.to_le_bytes());
+    file.extend_from_slice(&header.biPlanes.to_le_bytes());
+    file.extend_from_slice(&header.biBitCount.to_le_bytes());
+    file.extend_from_slice(&header.biCompression.to_le_bytes());
+    file.extend_from_slice(&header.biSizeImage.to_le_bytes());
+    // Resolution (unspecified) and palette (unused).
+    file.extend_from_slice(&[0; 16]);
+    file.extend_from_slice(&pixels);
+    Ok(file)
+}
@@ -0,0 +1,13 @@
+/// What to do after an operation failed and the user chose not to stop.
+///
+/// Returned in the [`Continue`](std::ops::ControlFlow::Continue) case of
+/// [`AbortRetryIgnore::control_flow`](crate::AbortRetryIgnore::control_flow) and
+/// [`CancelTryAgainContinue::control_flow`](crate::CancelTryAgainContinue::control_flow).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RetryOrSkip {
+    /// Try the operation again.
+    Retry,
+    /// Skip the operation and carry on.
+    Skip,
+}
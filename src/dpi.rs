@@ -0,0 +1,54 @@
+use windows_sys::{
+    s, w,
+    Win32::{
+        System::LibraryLoader::{GetModuleHandleW, GetProcAddress},
+        UI::HiDpi::{DPI_AWARENESS_CONTEXT, DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2},
+    },
+};
+
+/// The signature of `SetThreadDpiAwarenessContext`.
+type SetThreadDpiAwarenessContextFn =
+    unsafe extern "system" fn(DPI_AWARENESS_CONTEXT) -> DPI_AWARENESS_CONTEXT;
+
+/// Makes the current thread per-monitor DPI aware (V2) until it's dropped,
+/// then restores the previous DPI awareness.
+pub(crate) struct DpiAwarenessScope {
+    set_context: SetThreadDpiAwarenessContextFn,
+    previous: DPI_AWARENESS_CONTEXT,
+}
+
+impl DpiAwarenessScope {
+    /// Changes the DPI awareness of the current thread.
+    ///
+    /// Returns `None` if `SetThreadDpiAwarenessContext` isn't available (before Windows 10, version 1607)
+    /// or the context isn't supported (before Windows 10, version 1703).
+    pub(crate) fn enter() -> Option<Self> {
+        // `SetThreadDpiAwarenessContext` is loaded at runtime, so linking to it doesn't prevent
+        // the program from starting on older versions of Windows.
+        let set_context = unsafe {
+            let user32 = GetModuleHandleW(w!("user32.dll"));
+            if user32.is_null() {
+                return None;
+            }
+            let proc = GetProcAddress(user32, s!("SetThreadDpiAwarenessContext"))?;
+            std::mem::transmute::<
+                unsafe extern "system" fn() -> isize,
+                SetThreadDpiAwarenessContextFn,
+            >(proc)
+        };
+        let previous = unsafe { set_context(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2) };
+        if previous.is_null() {
+            return None;
+        }
+        Some(Self {
+            set_context,
+            previous,
+        })
+    }
+}
+
+impl Drop for DpiAwarenessScope {
+    fn drop(&mut self) {
+        unsafe { (self.set_context)(self.previous) };
+    }
+}
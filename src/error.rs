@@ -0,0 +1,182 @@
+use windows_sys::{
+    core::HRESULT,
+    Win32::{
+        Foundation::{ERROR_INVALID_PARAMETER, WIN32_ERROR},
+        UI::WindowsAndMessaging::MESSAGEBOX_RESULT,
+    },
+};
+
+/// An error that occurred while showing a message box.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum MsgBoxError {
+    /// Raw error returned by [GetLastError](https://learn.microsoft.com/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror).
+    Win32(WIN32_ERROR),
+    /// The text is longer than [MAX_TEXT_UTF16_LEN](crate::MAX_TEXT_UTF16_LEN) UTF-16 code units.
+    TextTooLong,
+    /// The title is longer than [MAX_TEXT_UTF16_LEN](crate::MAX_TEXT_UTF16_LEN) UTF-16 code units.
+    TitleTooLong,
+    /// COM couldn't be initialized (only returned by `show_on_thread_with_sta`).
+    Com(HRESULT),
+    /// The default button doesn't exist on the message box
+    /// (e.g. `DefaultButton3` on a message box with two buttons).
+    DefaultButtonOutOfRange,
+    /// `TaskDialogIndirect` failed or isn't available (only returned by `TaskDialog::show`).
+    TaskDialog(HRESULT),
+    /// The owner window belongs to another thread
+    /// (only returned with [`strict_threading`](crate::MessageBox::strict_threading)).
+    CrossThreadOwner,
+    /// The user didn't select the affirmative button
+    /// (only returned by [`show_confirmed`](crate::MessageBox::show_confirmed)).
+    Declined,
+}
+
+/// An invalid configuration of a message box, returned by [`validate`](crate::MessageBox::validate).
+///
+/// [show](crate::MessageBox::show) converts it to the corresponding [MsgBoxError].
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// An owner is combined with [`service_notification`](crate::MessageBox::service_notification).
+    OwnerWithServiceNotification,
+    /// An owner is combined with [`task_modal_current_thread`](crate::MessageBox::task_modal_current_thread).
+    OwnerWithTaskModal,
+    /// The default button doesn't exist on the message box.
+    DefaultButtonOutOfRange,
+    /// The text is longer than [MAX_TEXT_UTF16_LEN](crate::MAX_TEXT_UTF16_LEN) UTF-16 code units.
+    TextTooLong,
+    /// The title is longer than [MAX_TEXT_UTF16_LEN](crate::MAX_TEXT_UTF16_LEN) UTF-16 code units.
+    TitleTooLong,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::OwnerWithServiceNotification => {
+                "an owner can't be combined with a service notification"
+            }
+            Self::OwnerWithTaskModal => {
+                "an owner can't be combined with a task-modal message box for the current thread"
+            }
+            Self::DefaultButtonOutOfRange => "the default button doesn't exist on the message box",
+            Self::TextTooLong => "the text of the message box is too long",
+            Self::TitleTooLong => "the title of the message box is too long",
+        })
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Conflicting owners are converted to `ERROR_INVALID_PARAMETER`, which Win32 would return.
+impl From<ConfigError> for MsgBoxError {
+    fn from(value: ConfigError) -> Self {
+        match value {
+            ConfigError::OwnerWithServiceNotification | ConfigError::OwnerWithTaskModal => {
+                Self::Win32(ERROR_INVALID_PARAMETER)
+            }
+            ConfigError::DefaultButtonOutOfRange => Self::DefaultButtonOutOfRange,
+            ConfigError::TextTooLong => Self::TextTooLong,
+            ConfigError::TitleTooLong => Self::TitleTooLong,
+        }
+    }
+}
+
+impl From<WIN32_ERROR> for MsgBoxError {
+    fn from(value: WIN32_ERROR) -> Self {
+        Self::Win32(value)
+    }
+}
+
+impl std::fmt::Display for MsgBoxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Win32(code) => write!(f, "win32 error {code}"),
+            Self::TextTooLong => f.write_str("the text of the message box is too long"),
+            Self::TitleTooLong => f.write_str("the title of the message box is too long"),
+            Self::Com(hr) => write!(f, "failed to initialize COM (HRESULT {hr:#010x})"),
+            Self::DefaultButtonOutOfRange => {
+                f.write_str("the default button doesn't exist on the message box")
+            }
+            Self::TaskDialog(hr) => {
+                write!(f, "failed to show the task dialog (HRESULT {hr:#010x})")
+            }
+            Self::CrossThreadOwner => {
+                f.write_str("the owner of the message box belongs to another thread")
+            }
+            Self::Declined => f.write_str("the user declined the message box"),
+        }
+    }
+}
+
+impl std::error::Error for MsgBoxError {}
+
+/// Win32 errors are converted to OS errors with the same code.
+/// Other errors are wrapped in an error of kind [`InvalidInput`](std::io::ErrorKind::InvalidInput)
+/// or [`Other`](std::io::ErrorKind::Other).
+impl From<MsgBoxError> for std::io::Error {
+    fn from(value: MsgBoxError) -> Self {
+        use std::io::ErrorKind;
+
+        match value {
+            MsgBoxError::Win32(code) => Self::from_raw_os_error(code as i32),
+            MsgBoxError::TextTooLong
+            | MsgBoxError::TitleTooLong
+            | MsgBoxError::DefaultButtonOutOfRange
+            | MsgBoxError::CrossThreadOwner => Self::new(ErrorKind::InvalidInput, value),
+            MsgBoxError::Com(_) | MsgBoxError::TaskDialog(_) | MsgBoxError::Declined => {
+                Self::other(value)
+            }
+        }
+    }
+}
+
+/// Extension methods for [`win_msgbox::Result`](crate::Result).
+///
+/// [MsgBoxError] implements [Error](std::error::Error), so it can already be converted
+/// to error types like `anyhow::Error` with `?`.
+pub trait MsgBoxResultExt<T> {
+    /// Converts the error to an [io::Error](std::io::Error), keeping the OS error code of Win32 errors.
+    ///
+    /// ```
+    /// use win_msgbox::{MsgBoxError, MsgBoxResultExt};
+    /// use windows_sys::Win32::Foundation::ERROR_INVALID_PARAMETER;
+    ///
+    /// let result: win_msgbox::Result<()> = Err(MsgBoxError::Win32(ERROR_INVALID_PARAMETER));
+    /// let error = result.into_io().unwrap_err();
+    /// assert_eq!(error.raw_os_error(), Some(ERROR_INVALID_PARAMETER as i32));
+    ///
+    /// let result: win_msgbox::Result<()> = Err(MsgBoxError::TextTooLong);
+    /// assert_eq!(result.into_io().unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+    /// ```
+    fn into_io(self) -> std::io::Result<T>;
+}
+
+impl<T> MsgBoxResultExt<T> for Result<T, MsgBoxError> {
+    fn into_io(self) -> std::io::Result<T> {
+        self.map_err(Into::into)
+    }
+}
+
+/// A code returned by `MessageBoxW` that doesn't correspond to any button of an option.
+///
+/// Returned by `try_from_code` of the options (e.g. [`YesNo::try_from_code`](crate::YesNo::try_from_code)).
+///
+/// ```
+/// use win_msgbox::{Okay, UnknownResult, YesNo, YesNoCancel};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDHELP, IDYES};
+///
+/// assert_eq!(YesNo::try_from_code(IDYES), Ok(YesNo::Yes));
+/// assert_eq!(YesNo::try_from_code(IDHELP), Err(UnknownResult(IDHELP)));
+/// assert_eq!(YesNoCancel::try_from_code(0), Err(UnknownResult(0)));
+/// assert_eq!(Okay::try_from_code(0), Err(UnknownResult(0)));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct UnknownResult(pub MESSAGEBOX_RESULT);
+
+impl std::fmt::Display for UnknownResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown message box result {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownResult {}
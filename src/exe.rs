@@ -0,0 +1,70 @@
+use crate::text::Text;
+use std::{path::Path, ptr, slice, sync::OnceLock};
+use windows_sys::{
+    w,
+    Win32::{
+        Foundation::FALSE,
+        Storage::FileSystem::{GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW},
+    },
+};
+
+/// The name of the current executable, see [title_from_exe](crate::MessageBox::title_from_exe).
+///
+/// It's looked up once per process.
+pub(crate) fn exe_title() -> Option<&'static str> {
+    static TITLE: OnceLock<Option<String>> = OnceLock::new();
+    TITLE
+        .get_or_init(|| {
+            // Uses `GetModuleFileNameW` on Windows.
+            let path = std::env::current_exe().ok()?;
+            file_description(&path)
+                .or_else(|| Some(path.file_stem()?.to_string_lossy().into_owned()))
+        })
+        .as_deref()
+}
+
+/// Reads the `FileDescription` from the version info of the file at `path`,
+/// using the first language listed in the file.
+fn file_description(path: &Path) -> Option<String> {
+    let path = Text::Os(path.as_os_str()).to_wide();
+    unsafe {
+        let size = GetFileVersionInfoSizeW(path.as_ptr(), ptr::null_mut());
+        if size == 0 {
+            return None;
+        }
+        let mut info = vec![0u8; size as usize];
+        if GetFileVersionInfoW(path.as_ptr(), 0, size, info.as_mut_ptr().cast()) == FALSE {
+            return None;
+        }
+
+        let mut value = ptr::null_mut();
+        let mut len = 0;
+        // An array of (language, codepage) pairs, `len` is in bytes.
+        if VerQueryValueW(
+            info.as_ptr().cast(),
+            w!("\\VarFileInfo\\Translation"),
+            &mut value,
+            &mut len,
+        ) == FALSE
+            || (len as usize) < size_of::<[u16; 2]>()
+        {
+            return None;
+        }
+        let [language, codepage] = value.cast::<[u16; 2]>().read_unaligned();
+
+        let key: Vec<u16> =
+            format!("\\StringFileInfo\\{language:04x}{codepage:04x}\\FileDescription\0")
+                .encode_utf16()
+                .collect();
+        // `len` is in characters, including the null terminator.
+        if VerQueryValueW(info.as_ptr().cast(), key.as_ptr(), &mut value, &mut len) == FALSE
+            || len == 0
+        {
+            return None;
+        }
+        let description = slice::from_raw_parts(value.cast::<u16>(), len as usize);
+        let description = String::from_utf16_lossy(description);
+        let description = description.trim_end_matches('\0').trim();
+        (!description.is_empty()).then(|| description.to_owned())
+    }
+}
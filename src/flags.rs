@@ -0,0 +1,9 @@
+//! Re-exports of the `MB_*` modifiers that can be passed to [`with_flags`][crate::MessageBox::with_flags].
+//!
+//! The buttons and the icon are controlled by the type of the message box and [Icon][crate::Icon],
+//! so their flags aren't included here.
+pub use windows_sys::Win32::UI::WindowsAndMessaging::{
+    MB_APPLMODAL, MB_DEFAULT_DESKTOP_ONLY, MB_DEFBUTTON1, MB_DEFBUTTON2, MB_DEFBUTTON3,
+    MB_DEFBUTTON4, MB_HELP, MB_RIGHT, MB_RTLREADING, MB_SERVICE_NOTIFICATION, MB_SETFOREGROUND,
+    MB_SYSTEMMODAL, MB_TASKMODAL, MB_TOPMOST,
+};
@@ -0,0 +1,96 @@
+use std::{cell::Cell, panic::AssertUnwindSafe};
+use windows_sys::Win32::UI::Shell::HELPINFO;
+
+/// A request for help in a message box, passed to the handler of
+/// [`show_with_help_handler`](crate::MessageBox::show_with_help_handler).
+///
+/// ```
+/// use win_msgbox::HelpInfo;
+/// use windows_sys::Win32::{
+///     Foundation::POINT,
+///     UI::Shell::{HELPINFO, HELPINFO_WINDOW},
+/// };
+///
+/// let raw = HELPINFO {
+///     cbSize: size_of::<HELPINFO>() as u32,
+///     iContextType: HELPINFO_WINDOW,
+///     iCtrlId: 0,
+///     hItemHandle: std::ptr::null_mut(),
+///     dwContextId: 0,
+///     MousePos: POINT { x: 640, y: 360 },
+/// };
+/// let info = HelpInfo::from(&raw);
+/// assert_eq!(info.control_id, 0);
+/// assert_eq!(info.mouse_pos, (640, 360));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct HelpInfo {
+    /// The help context ID (`dwContextId`).
+    ///
+    /// For message boxes, this is the context ID of the message box, which is always `0`.
+    pub context_id: usize,
+    /// The ID of the window or control help was requested for (`iCtrlId`).
+    ///
+    /// For message boxes, this refers to the message box itself rather than one of its buttons.
+    pub control_id: i32,
+    /// The position of the mouse in screen coordinates when help was requested,
+    /// which is also set if help was requested through F1.
+    pub mouse_pos: (i32, i32),
+}
+
+impl From<&HELPINFO> for HelpInfo {
+    fn from(value: &HELPINFO) -> Self {
+        Self {
+            context_id: value.dwContextId,
+            control_id: value.iCtrlId,
+            mouse_pos: (value.MousePos.x, value.MousePos.y),
+        }
+    }
+}
+
+/// A handler borrowed by [with_handler], with its lifetime erased.
+type Handler = *mut (dyn FnMut(HelpInfo) + 'static);
+
+thread_local! {
+    /// The handler receiving help requests of message boxes on this thread, if any.
+    static HANDLER: Cell<Option<Handler>> = const { Cell::new(None) };
+}
+
+/// Restores the previous handler when dropped, even if showing the message box panicked.
+#[cfg(feature = "safe")]
+struct HandlerGuard(Option<Handler>);
+
+#[cfg(feature = "safe")]
+impl Drop for HandlerGuard {
+    fn drop(&mut self) {
+        HANDLER.set(self.0);
+    }
+}
+
+/// Calls `f` with `handler` receiving help requests of message boxes on this thread.
+#[cfg(feature = "safe")]
+pub(crate) fn with_handler<R>(handler: &mut dyn FnMut(HelpInfo), f: impl FnOnce() -> R) -> R {
+    // Safety: the handler is only reachable through `HANDLER` until the guard is dropped,
+    // while it's still borrowed.
+    let handler: Handler = unsafe { std::mem::transmute(handler as *mut dyn FnMut(HelpInfo)) };
+    let _guard = HandlerGuard(HANDLER.replace(Some(handler)));
+    f()
+}
+
+/// Checks if a handler receives help requests on this thread.
+pub(crate) fn has_handler() -> bool {
+    HANDLER.get().is_some()
+}
+
+/// The `MSGBOXCALLBACK` passing help requests to the handler.
+pub(crate) unsafe extern "system" fn call_handler(info: *mut HELPINFO) {
+    // The handler is removed while it runs, so it's not borrowed twice by nested message boxes.
+    let Some(handler) = HANDLER.take() else {
+        return;
+    };
+    if let Some(info) = info.as_ref() {
+        // This is called by Windows, where unwinding would abort the process, so a panic is caught.
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| (*handler)(HelpInfo::from(info))));
+    }
+    HANDLER.set(Some(handler));
+}
@@ -0,0 +1,371 @@
+use crate::{monitor::center_on_monitor, CustomIcon, MonitorSelector, Result};
+use std::{
+    cell::{Cell, RefCell},
+    panic::AssertUnwindSafe,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+#[cfg(feature = "safe")]
+use windows_sys::Win32::{
+    Foundation::RECT,
+    UI::WindowsAndMessaging::{GetWindowRect, IDTIMEOUT},
+};
+use windows_sys::Win32::{
+    Foundation::{GetLastError, HWND, LPARAM, LRESULT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::{
+        Input::KeyboardAndMouse::{EnableWindow, GetActiveWindow, VK_ESCAPE},
+        WindowsAndMessaging::{
+            CallNextHookEx, EndDialog, GetClassNameW, GetDlgItem, GetForegroundWindow,
+            GetWindowLongW, KillTimer, SendMessageW, SetForegroundWindow,
+            SetLayeredWindowAttributes, SetTimer, SetWindowLongW, SetWindowPos, SetWindowsHookExW,
+            UnhookWindowsHookEx, GWL_EXSTYLE, HCBT_ACTIVATE, HC_ACTION, HHOOK, HWND_TOPMOST,
+            IDCANCEL, IDCONTINUE, IDOK, LWA_ALPHA, STM_SETICON, SWP_NOACTIVATE, SWP_NOMOVE,
+            SWP_NOSIZE, SWP_NOZORDER, USER_TIMER_MAXIMUM, WH_CBT, WH_KEYBOARD, WS_EX_LAYERED,
+        },
+    },
+};
+
+/// An action that modifies a message box once it's activated.
+type OnActivate = Box<dyn FnOnce(HWND)>;
+
+/// A user callback receiving the message box once it's created.
+///
+/// It's shared between clones of a message box, so it's reference counted.
+pub(crate) type OnCreated = Arc<Mutex<dyn FnMut(HWND) + Send>>;
+
+thread_local! {
+    /// The action to run once the message box on this thread is activated for the first time.
+    static ON_ACTIVATE: RefCell<Option<OnActivate>> = const { RefCell::new(None) };
+    /// When the message box on this thread started to fade in and how long it fades in.
+    static FADE_IN: Cell<Option<(Instant, Duration)>> = const { Cell::new(None) };
+}
+
+/// The options of a message box that are applied through a [CbtHook] once it's activated.
+pub(crate) struct DialogHooks {
+    /// The position to move the message box to.
+    pub(crate) position: Option<(i32, i32)>,
+    /// The monitor to center the message box on (ignored if `position` is set).
+    pub(crate) monitor: Option<MonitorSelector>,
+    /// Whether to give the focus back to the current foreground window.
+    pub(crate) keep_foreground: bool,
+    /// Whether to move the message box back to the top periodically.
+    pub(crate) keep_topmost: bool,
+    /// How long the message box fades in.
+    pub(crate) fade_in: Option<Duration>,
+    /// How long the buttons of the message box are disabled.
+    pub(crate) min_display_time: Option<Duration>,
+    /// The icon replacing the system icon.
+    pub(crate) custom_icon: Option<CustomIcon>,
+    /// The user callback receiving the message box.
+    pub(crate) on_created: Option<OnCreated>,
+}
+
+/// A `WH_CBT` hook on the current thread, which is uninstalled when dropped.
+///
+/// Windows doesn't provide a way to configure the message box window before it's shown,
+/// so the hook waits for the first `HCBT_ACTIVATE` of a message box and modifies it there.
+pub(crate) struct CbtHook(HHOOK);
+
+impl CbtHook {
+    /// Installs the hook, calling `on_activate` with the message box once it's activated.
+    pub(crate) fn install(on_activate: impl FnOnce(HWND) + 'static) -> Result<Self> {
+        ON_ACTIVATE.set(Some(Box::new(on_activate)));
+        let hook = unsafe {
+            SetWindowsHookExW(
+                WH_CBT,
+                Some(cbt_proc),
+                std::ptr::null_mut(),
+                GetCurrentThreadId(),
+            )
+        };
+        if hook.is_null() {
+            ON_ACTIVATE.take();
+            return Err(unsafe { GetLastError() }.into());
+        }
+        Ok(Self(hook))
+    }
+
+    /// Installs the hook if any of the `hooks` need to be applied.
+    pub(crate) fn for_dialog(hooks: DialogHooks) -> Result<Option<Self>> {
+        let DialogHooks {
+            position,
+            monitor,
+            keep_foreground,
+            keep_topmost,
+            fade_in,
+            min_display_time,
+            custom_icon,
+            on_created,
+        } = hooks;
+        let foreground = if keep_foreground {
+            unsafe { GetForegroundWindow() }
+        } else {
+            std::ptr::null_mut()
+        };
+        if position.is_none()
+            && monitor.is_none()
+            && foreground.is_null()
+            && !keep_topmost
+            && fade_in.is_none()
+            && min_display_time.is_none()
+            && custom_icon.is_none()
+            && on_created.is_none()
+        {
+            return Ok(None);
+        }
+        Self::install(move |hwnd| {
+            if let Some((x, y)) = position {
+                move_window(hwnd, x, y);
+            } else if let Some(monitor) = monitor {
+                center_on_monitor(hwnd, monitor);
+            }
+            if !foreground.is_null() {
+                unsafe { SetForegroundWindow(foreground) };
+            }
+            if keep_topmost {
+                unsafe {
+                    SetTimer(
+                        hwnd,
+                        KEEP_TOPMOST_TIMER,
+                        KEEP_TOPMOST_INTERVAL,
+                        Some(raise_topmost),
+                    )
+                };
+            }
+            if let Some(CustomIcon(icon)) = custom_icon {
+                unsafe {
+                    SendMessageW(
+                        GetDlgItem(hwnd, ICON_CONTROL_ID),
+                        STM_SETICON,
+                        icon as WPARAM,
+                        0,
+                    )
+                };
+            }
+            if let Some(duration) = fade_in {
+                start_fade_in(hwnd, duration);
+            }
+            if let Some(duration) = min_display_time {
+                enable_buttons(hwnd, false);
+                let millis = duration.as_millis().min(USER_TIMER_MAXIMUM.into()) as u32;
+                unsafe {
+                    SetTimer(
+                        hwnd,
+                        ENABLE_BUTTONS_TIMER,
+                        millis,
+                        Some(enable_buttons_later),
+                    )
+                };
+            }
+            if let Some(on_created) = on_created {
+                // This runs inside `cbt_proc`, where unwinding would abort the process,
+                // so a panic is caught. It poisons the mutex, and a poisoned callback isn't called again.
+                let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    if let Ok(mut on_created) = on_created.lock() {
+                        on_created(hwnd);
+                    }
+                }));
+            }
+        })
+        .map(Some)
+    }
+}
+
+impl Drop for CbtHook {
+    fn drop(&mut self) {
+        unsafe { UnhookWindowsHookEx(self.0) };
+        ON_ACTIVATE.take();
+    }
+}
+
+unsafe extern "system" fn cbt_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code == HCBT_ACTIVATE as i32 && is_message_box(wparam as HWND) {
+        // The action is removed while it runs, so nested message boxes don't run it again.
+        if let Some(on_activate) = ON_ACTIVATE.take() {
+            on_activate(wparam as HWND);
+        }
+    }
+    CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam)
+}
+
+/// A `WH_KEYBOARD` hook on the current thread that closes the active message box when ESC is pressed.
+/// It's uninstalled when dropped.
+pub(crate) struct EscapeHook(HHOOK);
+
+impl EscapeHook {
+    pub(crate) fn install() -> Result<Self> {
+        let hook = unsafe {
+            SetWindowsHookExW(
+                WH_KEYBOARD,
+                Some(escape_proc),
+                std::ptr::null_mut(),
+                GetCurrentThreadId(),
+            )
+        };
+        if hook.is_null() {
+            return Err(unsafe { GetLastError() }.into());
+        }
+        Ok(Self(hook))
+    }
+}
+
+impl Drop for EscapeHook {
+    fn drop(&mut self) {
+        unsafe { UnhookWindowsHookEx(self.0) };
+    }
+}
+
+unsafe extern "system" fn escape_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    // Bit 31 of `lparam` is set when the key is released.
+    if code == HC_ACTION as i32 && wparam == VK_ESCAPE as WPARAM && lparam as u32 >> 31 == 0 {
+        let hwnd = GetActiveWindow();
+        if is_message_box(hwnd) {
+            // Message boxes without a Cancel button ignore ESC (and WM_CLOSE),
+            // so the dialog is ended directly.
+            EndDialog(hwnd, IDCANCEL as isize);
+            return 1;
+        }
+    }
+    CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam)
+}
+
+/// The ID of the static control showing the icon of a message box.
+const ICON_CONTROL_ID: i32 = 0x14;
+
+/// The timer ending a message box in [end_dialog_after].
+#[cfg(feature = "safe")]
+const END_DIALOG_TIMER: usize = 1;
+/// The timer moving a message box back to the top in [`CbtHook::for_dialog`].
+const KEEP_TOPMOST_TIMER: usize = 2;
+/// The interval in which a message box is moved back to the top in milliseconds.
+const KEEP_TOPMOST_INTERVAL: u32 = 250;
+/// The timer raising the opacity of a message box in [start_fade_in].
+const FADE_IN_TIMER: usize = 3;
+/// The interval in which the opacity of a message box is raised in milliseconds (about 60 FPS).
+const FADE_IN_INTERVAL: u32 = 16;
+/// The timer enabling the buttons of a message box again in [`CbtHook::for_dialog`].
+const ENABLE_BUTTONS_TIMER: usize = 4;
+
+/// Ends the message box `hwnd` with `IDTIMEOUT` after `millis` milliseconds.
+///
+/// The timer belongs to the message box, so it's destroyed together with it.
+#[cfg(feature = "safe")]
+pub(crate) fn end_dialog_after(hwnd: HWND, millis: u32) {
+    unsafe { SetTimer(hwnd, END_DIALOG_TIMER, millis, Some(end_dialog)) };
+}
+
+unsafe extern "system" fn raise_topmost(hwnd: HWND, _: u32, _: usize, _: u32) {
+    SetWindowPos(
+        hwnd,
+        HWND_TOPMOST,
+        0,
+        0,
+        0,
+        0,
+        SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+    );
+}
+
+/// Makes `hwnd` a fully transparent layered window.
+fn make_transparent(hwnd: HWND) {
+    unsafe {
+        SetWindowLongW(
+            hwnd,
+            GWL_EXSTYLE,
+            GetWindowLongW(hwnd, GWL_EXSTYLE) | WS_EX_LAYERED as i32,
+        );
+        SetLayeredWindowAttributes(hwnd, 0, 0, LWA_ALPHA);
+    }
+}
+
+/// Makes `hwnd` transparent and starts a timer fading it in over `duration`.
+fn start_fade_in(hwnd: HWND, duration: Duration) {
+    make_transparent(hwnd);
+    FADE_IN.set(Some((Instant::now(), duration)));
+    unsafe { SetTimer(hwnd, FADE_IN_TIMER, FADE_IN_INTERVAL, Some(fade_in_step)) };
+}
+
+unsafe extern "system" fn fade_in_step(hwnd: HWND, _: u32, _: usize, _: u32) {
+    let progress = FADE_IN.get().map_or(1.0, |(start, duration)| {
+        start.elapsed().as_secs_f32() / duration.as_secs_f32()
+    });
+    if progress < 1.0 {
+        SetLayeredWindowAttributes(hwnd, 0, (progress * 255.0) as u8, LWA_ALPHA);
+        return;
+    }
+    // Fully opaque: the window doesn't need to be layered anymore.
+    KillTimer(hwnd, FADE_IN_TIMER);
+    FADE_IN.take();
+    SetWindowLongW(
+        hwnd,
+        GWL_EXSTYLE,
+        GetWindowLongW(hwnd, GWL_EXSTYLE) & !(WS_EX_LAYERED as i32),
+    );
+}
+
+/// Enables or disables the buttons of the message box `hwnd`.
+///
+/// The buttons have the IDs of the responses (`IDOK` to `IDCONTINUE`).
+fn enable_buttons(hwnd: HWND, enable: bool) {
+    for id in IDOK..=IDCONTINUE {
+        let button = unsafe { GetDlgItem(hwnd, id) };
+        if !button.is_null() {
+            unsafe { EnableWindow(button, enable.into()) };
+        }
+    }
+}
+
+unsafe extern "system" fn enable_buttons_later(hwnd: HWND, _: u32, _: usize, _: u32) {
+    KillTimer(hwnd, ENABLE_BUTTONS_TIMER);
+    enable_buttons(hwnd, true);
+}
+
+#[cfg(feature = "safe")]
+unsafe extern "system" fn end_dialog(hwnd: HWND, _: u32, _: usize, _: u32) {
+    EndDialog(hwnd, IDTIMEOUT as isize);
+}
+
+/// Makes the message box `hwnd` transparent, ends it with `IDCANCEL`, and returns its size in pixels.
+#[cfg(feature = "safe")]
+pub(crate) fn measure_and_end(hwnd: HWND) -> (u32, u32) {
+    make_transparent(hwnd);
+    let mut rect = RECT {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    };
+    unsafe {
+        GetWindowRect(hwnd, &mut rect);
+        EndDialog(hwnd, IDCANCEL as isize);
+    }
+    (
+        rect.right.abs_diff(rect.left),
+        rect.bottom.abs_diff(rect.top),
+    )
+}
+
+/// Moves `hwnd` to `x`, `y` (in screen coordinates) without resizing or activating it.
+pub(crate) fn move_window(hwnd: HWND, x: i32, y: i32) {
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            std::ptr::null_mut(),
+            x,
+            y,
+            0,
+            0,
+            SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        )
+    };
+}
+
+/// Checks if `hwnd` has the window class of message boxes (`#32770`).
+pub(crate) fn is_message_box(hwnd: HWND) -> bool {
+    let mut class = [0u16; 8];
+    let len = unsafe { GetClassNameW(hwnd, class.as_mut_ptr(), class.len() as i32) };
+    "#32770"
+        .encode_utf16()
+        .eq(class[..len.max(0) as usize].iter().copied())
+}
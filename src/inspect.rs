@@ -0,0 +1,60 @@
+use crate::{MessageBox, Options, Result};
+
+/// A message box that passes its response to a closure, created by [inspect](MessageBox::inspect).
+#[derive(Clone)]
+#[must_use = "the message box isn't shown until `show` is called"]
+pub struct Inspect<'a, T, F> {
+    msgbox: MessageBox<'a, T>,
+    f: F,
+}
+
+impl<T, F> std::fmt::Debug for Inspect<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Inspect")
+            .field("msgbox", &self.msgbox)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: Options, F: FnOnce(&Result<T>)> Inspect<'a, T, F> {
+    /// Shows the message box, calls the closure with the response, and returns the response.
+    ///
+    /// See [`MessageBox::show`] for details.
+    pub fn show(self) -> Result<T> {
+        let response = self.msgbox.show();
+        (self.f)(&response);
+        response
+    }
+}
+
+impl<'a, T> MessageBox<'a, T> {
+    /// Calls `f` with the response (or error) once the message box is closed,
+    /// without changing the response - like [`Iterator::inspect`].
+    ///
+    /// This is useful for side effects such as logging or analytics.
+    /// The closure is stored in the returned [Inspect] without allocating,
+    /// so this should be the last call before [show](Inspect::show).
+    ///
+    /// ```
+    /// # #[cfg(all(windows, feature = "testing"))]
+    /// # {
+    /// use std::cell::Cell;
+    /// use win_msgbox::{testing, MessageBox, YesNo};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::IDNO;
+    ///
+    /// let calls = Cell::new(0);
+    /// testing::push_response(IDNO);
+    /// let response = MessageBox::<YesNo>::question("Send usage statistics?")
+    ///     .inspect(|response| {
+    ///         assert_eq!(*response, Ok(YesNo::No));
+    ///         calls.set(calls.get() + 1);
+    ///     })
+    ///     .show();
+    /// assert_eq!(response, Ok(YesNo::No));
+    /// assert_eq!(calls.get(), 1);
+    /// # }
+    /// ```
+    pub fn inspect<F: FnOnce(&Result<T>)>(self, f: F) -> Inspect<'a, T, F> {
+        Inspect { msgbox: self, f }
+    }
+}
@@ -35,43 +35,170 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! To import the commonly used items at once, use the [prelude]:
+//!
+//! ```no_run
+//! use win_msgbox::prelude::*;
+//!
+//! # fn main() -> Result<()> {
+//! if show::<YesNo>("Do you want to continue?")? == YesNo::No {
+//!     println!("Stopping");
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! ## Features
+//!
+//! - `capture`: Adds `MessageBox::show_and_capture` to save a screenshot of a message box.
+//! - `com`: Adds `MessageBox::show_on_thread_with_sta` to show message boxes in a single-threaded COM apartment.
+//! - `log`: Adds `MessageBox::show_and_log` to log message boxes and their responses,
+//!   and `MessageBox::from_record` to show log records.
+//! - `safe` (default): Adds [MessageBox] and the functions showing it (e.g. [show]).
+//!   Without it (`default-features = false`), only the [raw] module, the options, and the shared types like [Icon] are available.
+//!   `capture`, `com`, `log`, and `tracing` enable it.
+//! - `serde`: Implements `Serialize` and `Deserialize` for [Icon], [Modal], [DefaultButton], [Severity], [MonitorSelector], and all options.
+//! - `taskdialog`: Adds `TaskDialog` for buttons with custom captions.
+//!   This requires version 6 of the common controls (enabled through an application manifest).
+//! - `testing`: Adds the `testing` module to queue canned responses instead of showing message boxes.
+//! - `tracing`: Shows message boxes inside a `msgbox` span.
+//!
+//! With `serde`, the variant names are used as the serialized form:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use win_msgbox::{Icon, YesNo};
+//!
+//! assert_eq!(serde_json::to_string(&Icon::Warning).unwrap(), r#""Warning""#);
+//! assert_eq!(serde_json::from_str::<YesNo>(r#""No""#).unwrap(), YesNo::No);
+//! # }
+//! ```
 #![deny(missing_docs)]
 #![deny(clippy::cargo)]
-use std::marker::PhantomData;
+// The docs of the shared items link to the safe API. Without the `safe` feature, these links can't be resolved
+// and are rendered as plain text instead.
+#![cfg_attr(not(feature = "safe"), allow(rustdoc::broken_intra_doc_links))]
+#[cfg(feature = "safe")]
+use dpi::DpiAwarenessScope;
+#[cfg(feature = "safe")]
+use hook::{end_dialog_after, CbtHook, DialogHooks, EscapeHook, OnCreated};
+use std::cell::Cell;
+#[cfg(feature = "safe")]
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    marker::PhantomData,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+#[cfg(feature = "safe")]
+use text::Text;
+#[cfg(feature = "safe")]
 use windows_sys::Win32::{
-    Foundation::{GetLastError, HWND},
+    Foundation::{GetLastError, SetLastError, ERROR_NOT_SUPPORTED},
+    Media::Audio::{PlaySoundW, SND_ASYNC, SND_FILENAME, SND_NODEFAULT},
+    System::{Diagnostics::Debug::MessageBeep, Threading::GetCurrentThreadId},
     UI::WindowsAndMessaging::{
-        MessageBoxW, MB_APPLMODAL, MB_DEFAULT_DESKTOP_ONLY, MB_DEFBUTTON1, MB_DEFBUTTON2,
-        MB_DEFBUTTON3, MB_DEFBUTTON4, MB_HELP, MB_ICONASTERISK, MB_ICONERROR, MB_ICONEXCLAMATION,
-        MB_ICONHAND, MB_ICONINFORMATION, MB_ICONQUESTION, MB_ICONSTOP, MB_ICONWARNING, MB_RIGHT,
-        MB_RTLREADING, MB_SERVICE_NOTIFICATION, MB_SETFOREGROUND, MB_SYSTEMMODAL, MB_TASKMODAL,
-        MB_TOPMOST, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+        FindWindowW, GetForegroundWindow, GetWindowThreadProcessId, IsWindow, SetForegroundWindow,
+        MB_ABORTRETRYIGNORE, MB_CANCELTRYCONTINUE, MB_DEFAULT_DESKTOP_ONLY, MB_OKCANCEL,
+        MB_RETRYCANCEL, MB_RIGHT, MB_RTLREADING, MB_SERVICE_NOTIFICATION, MB_SETFOREGROUND,
+        MB_TOPMOST, MB_YESNO, MB_YESNOCANCEL, USER_TIMER_MAXIMUM,
+    },
+};
+use windows_sys::{
+    core::PCWSTR,
+    Win32::{
+        Foundation::HWND,
+        UI::WindowsAndMessaging::{
+            LoadIconW, MessageBoxExW, MessageBoxIndirectW, MessageBoxW, HICON, IDABORT, IDASYNC,
+            IDCANCEL, IDCLOSE, IDCONTINUE, IDHELP, IDIGNORE, IDI_ERROR, IDI_INFORMATION,
+            IDI_QUESTION, IDI_WARNING, IDNO, IDOK, IDRETRY, IDTIMEOUT, IDTRYAGAIN, IDYES,
+            MB_APPLMODAL, MB_DEFBUTTON1, MB_DEFBUTTON2, MB_DEFBUTTON3, MB_DEFBUTTON4, MB_DEFMASK,
+            MB_HELP, MB_ICONASTERISK, MB_ICONERROR, MB_ICONEXCLAMATION, MB_ICONHAND,
+            MB_ICONINFORMATION, MB_ICONQUESTION, MB_ICONSTOP, MB_ICONWARNING, MB_OK,
+            MB_SYSTEMMODAL, MB_TASKMODAL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE, MSGBOXPARAMSW,
+        },
     },
 };
 
 mod abort_retry_ignore;
 mod cancel_try_again_continue;
+#[cfg(feature = "capture")]
+mod capture;
+mod control_flow;
+#[cfg(feature = "safe")]
+mod dpi;
+mod error;
+#[cfg(feature = "safe")]
+mod exe;
+pub mod flags;
+mod help;
+mod hook;
+#[cfg(feature = "safe")]
+mod inspect;
+mod monitor;
 mod okay;
 mod okay_cancel;
+#[cfg(feature = "safe")]
+mod owned;
+pub mod prelude;
+#[cfg(feature = "safe")]
+mod prepared;
 pub mod raw;
 mod retry_cancel;
+#[cfg(feature = "safe")]
+mod spawn;
+#[cfg(feature = "taskdialog")]
+mod task_dialog;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod text;
+#[cfg(feature = "tracing")]
+mod trace;
 mod yes_no;
 mod yes_no_cancel;
 
 pub use abort_retry_ignore::*;
 pub use cancel_try_again_continue::*;
+pub use control_flow::RetryOrSkip;
+pub use error::*;
+pub use help::HelpInfo;
+#[cfg(feature = "safe")]
+pub use inspect::Inspect;
+pub use monitor::MonitorSelector;
 pub use okay::*;
 pub use okay_cancel::*;
+#[cfg(feature = "safe")]
+pub use owned::OwnedMessageBox;
+#[cfg(feature = "safe")]
+pub use prepared::PreparedMessageBox;
 pub use retry_cancel::*;
+#[cfg(feature = "safe")]
+pub use spawn::{DialogBatch, DialogCloser, MsgBoxRequest};
+#[cfg(feature = "taskdialog")]
+pub use task_dialog::TaskDialog;
 pub use yes_no::*;
 pub use yes_no_cancel::*;
 
-/// Raw error returned by [GetLastError](https://learn.microsoft.com/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror).
-pub type Error = windows_sys::Win32::Foundation::WIN32_ERROR;
+/// The error returned when showing a message box.
+pub type Error = MsgBoxError;
 
 /// Convenience wrapper type for a `Result<T, win_msgbox::Error>`.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// The maximum length of the text and title of a message box in UTF-16 code units.
+///
+/// Longer strings are rejected by [show](MessageBox::show) with [`MsgBoxError::TextTooLong`]
+/// or [`MsgBoxError::TitleTooLong`] before calling into Win32.
+pub const MAX_TEXT_UTF16_LEN: usize = 32_767;
+
+/// The maximum length of texts (including the null terminator) that are encoded on the stack when shown.
+#[cfg(feature = "safe")]
+const SHORT_TEXT_LEN: usize = 256;
+
 /// This trait is implemented for all possible options.
 ///
 /// Available are:
@@ -83,13 +210,313 @@ pub type Result<T> = core::result::Result<T, Error>;
 /// - [**Retry**, and **Cancel**](RetryCancel)
 /// - [**Yes**, and **No**](YesNo)
 /// - [**Yes**, **No**, and **Cancel**](YesNoCancel)
+///
+/// The flags of an option are available in `const` contexts through [`FLAGS`](Self::FLAGS):
+///
+/// ```
+/// use win_msgbox::{flags::MB_TOPMOST, Options, YesNo};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{MB_YESNO, MESSAGEBOX_STYLE};
+///
+/// const STYLE: MESSAGEBOX_STYLE = YesNo::FLAGS | MB_TOPMOST;
+/// assert_eq!(STYLE, MB_YESNO | MB_TOPMOST);
+/// ```
+///
+/// The buttons are described by [`BUTTON_COUNT`](Self::BUTTON_COUNT) and [`CAPTIONS`](Self::CAPTIONS),
+/// so generic code can inspect them without an instance:
+///
+/// ```
+/// use win_msgbox::{
+///     AbortRetryIgnore, CancelTryAgainContinue, Okay, OkayCancel, Options, RetryCancel, YesNo,
+///     YesNoCancel,
+/// };
+///
+/// fn buttons<T: Options>() -> (usize, &'static [&'static str]) {
+///     (T::BUTTON_COUNT, T::CAPTIONS)
+/// }
+///
+/// for (count, captions) in [
+///     buttons::<AbortRetryIgnore>(),
+///     buttons::<CancelTryAgainContinue>(),
+///     buttons::<Okay>(),
+///     buttons::<OkayCancel>(),
+///     buttons::<RetryCancel>(),
+///     buttons::<YesNo>(),
+///     buttons::<YesNoCancel>(),
+/// ] {
+///     assert_eq!(count, captions.len());
+/// }
+/// ```
 pub trait Options: From<MESSAGEBOX_RESULT> {
     /// The flags this option requires to be shown.
-    fn flags() -> MESSAGEBOX_STYLE;
+    ///
+    /// Trait methods can't be `const fn`, so this is an associated constant.
+    const FLAGS: MESSAGEBOX_STYLE;
+
+    /// The flags this option requires to be shown, same as [`FLAGS`](Self::FLAGS).
+    fn flags() -> MESSAGEBOX_STYLE {
+        Self::FLAGS
+    }
+
+    /// The (English) captions of the buttons this option shows in order (excluding the Help button).
+    ///
+    /// They match the [Display](std::fmt::Display) implementation of the options.
+    const CAPTIONS: &'static [&'static str];
+
+    /// The number of buttons this option shows (excluding the Help button).
+    ///
+    /// This is the number of [`CAPTIONS`](Self::CAPTIONS) by default.
+    const BUTTON_COUNT: usize = Self::CAPTIONS.len();
+
+    /// The captions of the buttons this option shows, same as [`CAPTIONS`](Self::CAPTIONS).
+    fn captions() -> &'static [&'static str] {
+        Self::CAPTIONS
+    }
+
+    /// The button that's the safest choice if the user confirms the message box without reading it
+    /// (e.g. **No** or **Cancel**).
+    fn safe_button() -> DefaultButton;
+}
+
+/// An option with an affirmative button, used by [show_confirmed](MessageBox::show_confirmed).
+pub trait Confirmable: Options {
+    /// Checks if the user selected the affirmative button.
+    fn is_confirmed(&self) -> bool;
+}
+
+/// Checks that the default button in `style` refers to an existing button.
+///
+/// The Help button added by [`with_help`](MessageBox::with_help) counts as the last button.
+pub(crate) fn check_default_button<T: Options>(
+    style: MESSAGEBOX_STYLE,
+) -> core::result::Result<(), ConfigError> {
+    let index = match style & MB_DEFMASK {
+        MB_DEFBUTTON1 => 1,
+        MB_DEFBUTTON2 => 2,
+        MB_DEFBUTTON3 => 3,
+        MB_DEFBUTTON4 => 4,
+        _ => return Err(ConfigError::DefaultButtonOutOfRange),
+    };
+    let count = T::BUTTON_COUNT + usize::from(style & MB_HELP != 0);
+    if index > count {
+        return Err(ConfigError::DefaultButtonOutOfRange);
+    }
+    Ok(())
+}
+
+/// Checks if `hwnd` (if not null) was created by another thread than the current one.
+///
+/// An invalid window has no thread (`0`), it's left to `MessageBoxW` to report the error.
+#[cfg(feature = "safe")]
+fn owned_by_other_thread(hwnd: HWND) -> bool {
+    if hwnd.is_null() {
+        return false;
+    }
+    let thread_id = unsafe { GetWindowThreadProcessId(hwnd, std::ptr::null_mut()) };
+    thread_id != 0 && thread_id != unsafe { GetCurrentThreadId() }
+}
+
+/// Rejects an owner of another thread if `strict_threading` is set, otherwise warns about it in debug builds.
+#[cfg(feature = "safe")]
+pub(crate) fn check_owner_thread(hwnd: HWND, strict_threading: bool) -> Result<()> {
+    if !owned_by_other_thread(hwnd) {
+        return Ok(());
+    }
+    if strict_threading {
+        return Err(MsgBoxError::CrossThreadOwner);
+    }
+    #[cfg(all(feature = "tracing", debug_assertions))]
+    tracing::warn!("showing a message box owned by a window of another thread");
+    #[cfg(all(not(feature = "tracing"), debug_assertions))]
+    eprintln!("win-msgbox: showing a message box owned by a window of another thread, see `MessageBox::strict_threading`");
+    Ok(())
+}
+
+/// Calls [MessageBoxW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxw),
+/// or [MessageBoxExW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxexw) if a `language` is set,
+/// unless a response is queued in the `testing` module.
+///
+/// If a help handler is set on this thread (see [`MessageBox::show_with_help_handler`]),
+/// [MessageBoxIndirectW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxindirectw) is called instead.
+///
+/// Successful results are recorded for [last_result].
+///
+/// ### Safety
+///
+/// `text` and `title` (if not null) must point to a valid 16 bit, null terminated string.
+pub(crate) unsafe fn message_box_w(
+    hwnd: HWND,
+    text: PCWSTR,
+    title: PCWSTR,
+    style: MESSAGEBOX_STYLE,
+    language: Option<u16>,
+) -> MESSAGEBOX_RESULT {
+    let code = call_message_box(hwnd, text, title, style, language);
+    if code != 0 {
+        LAST_RESULT.set(Some(code));
+    }
+    code
+}
+
+/// Calls the message box function selected by [message_box_w].
+///
+/// ### Safety
+///
+/// See [message_box_w].
+unsafe fn call_message_box(
+    hwnd: HWND,
+    text: PCWSTR,
+    title: PCWSTR,
+    style: MESSAGEBOX_STYLE,
+    language: Option<u16>,
+) -> MESSAGEBOX_RESULT {
+    #[cfg(feature = "testing")]
+    if let Some(code) = testing::pop_response() {
+        return code;
+    }
+    let _showing = ShowingGuard::enter();
+    if help::has_handler() {
+        let params = MSGBOXPARAMSW {
+            cbSize: std::mem::size_of::<MSGBOXPARAMSW>() as u32,
+            hwndOwner: hwnd,
+            hInstance: std::ptr::null_mut(),
+            lpszText: text,
+            lpszCaption: title,
+            dwStyle: style,
+            lpszIcon: std::ptr::null(),
+            dwContextHelpId: 0,
+            lpfnMsgBoxCallback: Some(help::call_handler),
+            dwLanguageId: language.map_or(0, u32::from),
+        };
+        return MessageBoxIndirectW(&params);
+    }
+    match language {
+        Some(language) => MessageBoxExW(hwnd, text, title, style, language),
+        None => MessageBoxW(hwnd, text, title, style),
+    }
+}
+
+thread_local! {
+    /// The number of message boxes currently shown on this thread.
+    static SHOWING: Cell<usize> = const { Cell::new(0) };
+    /// The code returned by the last message box shown successfully on this thread.
+    static LAST_RESULT: Cell<Option<MESSAGEBOX_RESULT>> = const { Cell::new(None) };
+}
+
+/// Counts a message box as shown on this thread until it's dropped.
+struct ShowingGuard;
+
+impl ShowingGuard {
+    fn enter() -> Self {
+        SHOWING.set(SHOWING.get() + 1);
+        Self
+    }
+}
+
+impl Drop for ShowingGuard {
+    fn drop(&mut self) {
+        SHOWING.set(SHOWING.get() - 1);
+    }
+}
+
+/// Returns `true` if a message box is currently shown on this thread.
+///
+/// While a message box is shown, the thread keeps dispatching messages to its other windows,
+/// so this can be used in window procedures (or callbacks like [`on_created`](MessageBox::on_created))
+/// to avoid stacking message boxes.
+///
+/// ```
+/// assert!(!win_msgbox::is_showing());
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::{testing, Okay};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::IDOK;
+///
+/// testing::push_response(IDOK);
+/// win_msgbox::show::<Okay>("Done").unwrap();
+/// // The message box is closed once `show` returns.
+/// assert!(!win_msgbox::is_showing());
+/// # }
+/// ```
+pub fn is_showing() -> bool {
+    SHOWING.get() > 0
+}
+
+/// Returns the raw code of the last message box shown successfully on this thread, e.g. for crash reports.
+///
+/// The code is recorded by all ways of showing a message box (including the [raw] interface and canned responses from the `testing` module)
+/// once the message box is closed. Failing to show a message box doesn't overwrite the code.
+/// Message boxes shown on other threads (e.g. through [`spawn_cancellable`](MessageBox::spawn_cancellable))
+/// are recorded on their thread.
+///
+/// ```
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::{testing, YesNo};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::IDNO;
+///
+/// testing::push_response(IDNO);
+/// win_msgbox::show::<YesNo>("Send a crash report?").unwrap();
+/// assert_eq!(win_msgbox::last_result(), Some(IDNO));
+/// # }
+/// ```
+pub fn last_result() -> Option<MESSAGEBOX_RESULT> {
+    LAST_RESULT.get()
+}
+
+/// Converts a code returned by `MessageBoxW` to the option `T`, like [`T::from`](From::from).
+///
+/// Codes `T` can't produce are mapped to a fallback (see the [From] implementation of each option).
+/// Use `try_from_code` on the options to reject them instead.
+///
+/// ```
+/// use win_msgbox::{OkayCancel, YesNo};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDOK, IDYES};
+///
+/// assert_eq!(win_msgbox::parse_result_code::<YesNo>(IDYES), YesNo::Yes);
+/// assert_eq!(win_msgbox::parse_result_code::<OkayCancel>(IDYES), OkayCancel::Cancel);
+/// assert_eq!(win_msgbox::parse_result_code::<OkayCancel>(IDOK), OkayCancel::Okay);
+/// ```
+pub fn parse_result_code<T: Options>(code: MESSAGEBOX_RESULT) -> T {
+    T::from(code)
+}
+
+/// The name of the constant for a code returned by `MessageBoxW` (e.g. `"IDYES"`), or `"UNKNOWN"`.
+///
+/// ```
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDTIMEOUT, IDYES};
+///
+/// assert_eq!(win_msgbox::classify(IDYES), "IDYES");
+/// assert_eq!(win_msgbox::classify(IDTIMEOUT), "IDTIMEOUT");
+/// assert_eq!(win_msgbox::classify(0), "UNKNOWN");
+/// ```
+pub fn classify(code: MESSAGEBOX_RESULT) -> &'static str {
+    match code {
+        IDOK => "IDOK",
+        IDCANCEL => "IDCANCEL",
+        IDABORT => "IDABORT",
+        IDRETRY => "IDRETRY",
+        IDIGNORE => "IDIGNORE",
+        IDYES => "IDYES",
+        IDNO => "IDNO",
+        IDCLOSE => "IDCLOSE",
+        IDHELP => "IDHELP",
+        IDTRYAGAIN => "IDTRYAGAIN",
+        IDCONTINUE => "IDCONTINUE",
+        IDTIMEOUT => "IDTIMEOUT",
+        IDASYNC => "IDASYNC",
+        _ => "UNKNOWN",
+    }
 }
 
+/// An icon loaded by the application (e.g. through `LoadImageW`), shown through [icon_handle](MessageBox::icon_handle).
+///
+/// The icon isn't destroyed by the message box, it must stay valid until the message box is closed.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct CustomIcon(pub HICON);
+
 /// The icon to be displayed in a message box.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)] // = MESSAGEBOX_STYLE
 pub enum Icon {
     /// An exclamation-point icon appears in the message box.
@@ -117,6 +544,121 @@ pub enum Icon {
 }
 
 impl Icon {
+    /// All icons in declaration order.
+    ///
+    /// ```
+    /// use win_msgbox::Icon;
+    ///
+    /// assert_eq!(Icon::ALL.len(), 8);
+    /// assert_eq!(Icon::all().next(), Some(Icon::Exclamation));
+    /// ```
+    pub const ALL: [Icon; 8] = [
+        Icon::Exclamation,
+        Icon::Warning,
+        Icon::Information,
+        Icon::Asterisk,
+        Icon::Question,
+        Icon::Stop,
+        Icon::Error,
+        Icon::Hand,
+    ];
+
+    /// Returns an iterator over [all](Self::ALL) icons in declaration order.
+    pub fn all() -> impl Iterator<Item = Icon> {
+        Self::ALL.into_iter()
+    }
+
+    /// The name of this icon (e.g. `"Warning"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Icon::Exclamation => "Exclamation",
+            Icon::Warning => "Warning",
+            Icon::Information => "Information",
+            Icon::Asterisk => "Asterisk",
+            Icon::Question => "Question",
+            Icon::Stop => "Stop",
+            Icon::Error => "Error",
+            Icon::Hand => "Hand",
+        }
+    }
+
+    /// Returns the representative variant of the icons that look the same:
+    ///
+    /// - [`Exclamation`](Self::Exclamation) and [`Warning`](Self::Warning) → [`Warning`](Self::Warning)
+    /// - [`Information`](Self::Information) and [`Asterisk`](Self::Asterisk) → [`Information`](Self::Information)
+    /// - [`Question`](Self::Question) → [`Question`](Self::Question)
+    /// - [`Stop`](Self::Stop), [`Error`](Self::Error), and [`Hand`](Self::Hand) → [`Error`](Self::Error)
+    ///
+    /// ```
+    /// use win_msgbox::Icon;
+    ///
+    /// assert_eq!(Icon::Hand.canonical(), Icon::Error);
+    /// assert_eq!(Icon::Asterisk.canonical(), Icon::Information);
+    /// ```
+    pub fn canonical(self) -> Icon {
+        match self {
+            Icon::Exclamation | Icon::Warning => Icon::Warning,
+            Icon::Information | Icon::Asterisk => Icon::Information,
+            Icon::Question => Icon::Question,
+            Icon::Stop | Icon::Error | Icon::Hand => Icon::Error,
+        }
+    }
+
+    /// Returns `true` if this is a stop-sign icon ([`Stop`](Self::Stop), [`Error`](Self::Error), or [`Hand`](Self::Hand)).
+    pub fn is_error(self) -> bool {
+        self.canonical() == Icon::Error
+    }
+
+    /// Returns the more severe icon of `self` and `other` (see the [Ord] implementation).
+    ///
+    /// ```
+    /// use win_msgbox::Icon;
+    ///
+    /// let statuses = [Icon::Information, Icon::Warning, Icon::Question];
+    /// let worst = statuses.into_iter().fold(Icon::Information, Icon::max);
+    /// assert_eq!(worst, Icon::Warning);
+    /// ```
+    pub fn max(self, other: Icon) -> Icon {
+        Ord::max(self, other)
+    }
+
+    /// The severity of the icon from `0` (least severe) to `3` (most severe).
+    fn severity_rank(self) -> u8 {
+        match self.canonical() {
+            Icon::Information => 0,
+            Icon::Question => 1,
+            Icon::Warning => 2,
+            _ => 3,
+        }
+    }
+
+    /// Loads the system icon a message box with this icon shows, e.g. to use the same icon in other windows.
+    ///
+    /// Icons that [look the same](Self::canonical) share the handle, which is owned by the system
+    /// and must not be destroyed (e.g. through `DestroyIcon`).
+    /// The handle is null if the icon couldn't be loaded.
+    ///
+    /// ```
+    /// # #[cfg(windows)]
+    /// # {
+    /// use win_msgbox::Icon;
+    ///
+    /// for icon in Icon::all() {
+    ///     assert!(!icon.to_hicon().is_null());
+    /// }
+    /// assert_eq!(Icon::Hand.to_hicon(), Icon::Error.to_hicon());
+    /// # }
+    /// ```
+    pub fn to_hicon(self) -> HICON {
+        let id = match self.canonical() {
+            Icon::Warning => IDI_WARNING,
+            Icon::Question => IDI_QUESTION,
+            Icon::Error => IDI_ERROR,
+            _ => IDI_INFORMATION,
+        };
+        unsafe { LoadIconW(std::ptr::null_mut(), id) }
+    }
+
     fn style(self) -> MESSAGEBOX_STYLE {
         match self {
             Icon::Exclamation => MB_ICONEXCLAMATION,
@@ -131,8 +673,89 @@ impl Icon {
     }
 }
 
+/// A system sound played through [MessageBeep](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messagebeep).
+///
+/// The sounds are identified by the `SystemAsterisk`, `SystemExclamation`, `SystemHand`,
+/// `SystemQuestion`, and `SystemDefault` entries in the registry.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[repr(u32)] // = MESSAGEBOX_STYLE
+pub enum MessageBeepSound {
+    /// A simple beep. If the sound card is not available, the sound is generated using the speaker.
+    Simple = 0xFFFF_FFFF,
+    /// The sound specified as the Windows **Default Beep** sound.
+    Ok = MB_OK,
+    /// The sound specified as the Windows **Asterisk** sound.
+    Asterisk = MB_ICONASTERISK,
+    /// The sound specified as the Windows **Exclamation** sound.
+    Exclamation = MB_ICONEXCLAMATION,
+    /// The sound specified as the Windows **Critical Stop** sound.
+    Hand = MB_ICONHAND,
+    /// The sound specified as the Windows **Question** sound.
+    Question = MB_ICONQUESTION,
+}
+
+/// Icons are ordered by severity: [`Information`](Icon::Information) < [`Question`](Icon::Question)
+/// < [`Warning`](Icon::Warning) < [`Error`](Icon::Error).
+///
+/// Icons that [look the same](Icon::canonical) are ordered by their declaration order,
+/// so the ordering is consistent with [Eq]: [`Information`](Icon::Information) < [`Asterisk`](Icon::Asterisk),
+/// [`Exclamation`](Icon::Exclamation) < [`Warning`](Icon::Warning),
+/// and [`Stop`](Icon::Stop) < [`Error`](Icon::Error) < [`Hand`](Icon::Hand).
+///
+/// ```
+/// use win_msgbox::Icon;
+///
+/// assert!(Icon::Information < Icon::Question);
+/// assert!(Icon::Question < Icon::Warning);
+/// assert!(Icon::Warning < Icon::Error);
+/// assert!(Icon::Exclamation < Icon::Stop);
+/// assert!(Icon::Information < Icon::Asterisk);
+///
+/// let mut icons = Icon::ALL;
+/// icons.sort();
+/// assert_eq!(
+///     icons,
+///     [
+///         Icon::Information,
+///         Icon::Asterisk,
+///         Icon::Question,
+///         Icon::Exclamation,
+///         Icon::Warning,
+///         Icon::Stop,
+///         Icon::Error,
+///         Icon::Hand,
+///     ]
+/// );
+/// ```
+impl Ord for Icon {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let key = |icon: Icon| (icon.severity_rank(), icon as u32);
+        key(*self).cmp(&key(*other))
+    }
+}
+
+impl PartialOrd for Icon {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The sound played when a message box is shown, set through [`with_sound`](MessageBox::with_sound).
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Sound<'a> {
+    /// The system plays the sound associated with the [Icon] (if any).
+    #[default]
+    Default,
+    /// No sound is played. Win32 couples the sound to the icon, so the message box is shown without an icon.
+    None,
+    /// The `.wav` file at this path is played asynchronously through
+    /// [PlaySoundW](https://learn.microsoft.com/previous-versions/dd743680(v=vs.85)) right before the message box is shown.
+    Custom(&'a str),
+}
+
 /// Specifies the modality of the dialog box.
 #[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)] // = MESSAGEBOX_STYLE
 pub enum Modal {
     /// The user must respond to the message box before continuing work in the window identified by the [`hwnd`](MessageBox::hwnd).
@@ -141,6 +764,8 @@ pub enum Modal {
     /// the user may be able to move to other windows within the thread.
     /// All child windows of the parent of the message box are automatically disabled,
     /// but pop-up windows are not.
+    ///
+    /// This is the default. `MB_APPLMODAL` is `0`, so it's the same as setting no modality at all.
     #[default]
     Application = MB_APPLMODAL,
     /// Same as [`Application`](Self::Application) except that the message box has the `WS_EX_TOPMOST` style.
@@ -152,6 +777,8 @@ pub enum Modal {
     /// if the [`hwnd`](MessageBox::hwnd) parameter is `0`. Use this flag when the calling application
     /// or library does not have a window handle available but still needs to prevent input to other windows in the calling thread
     /// without suspending other threads.
+    ///
+    /// Use [`task_modal_current_thread`](MessageBox::task_modal_current_thread) to make sure there's no owner.
     Task = MB_TASKMODAL,
 }
 
@@ -159,6 +786,7 @@ pub enum Modal {
 ///
 /// The meaning of the nth button is determined by the type ([Options]).
 #[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)] // = MESSAGEBOX_STYLE
 pub enum DefaultButton {
     /// The first button is the default button.
@@ -172,25 +800,206 @@ pub enum DefaultButton {
     DefaultButton4 = MB_DEFBUTTON4,
 }
 
+impl DefaultButton {
+    /// Creates the default button from its 1-based index,
+    /// returning `None` if `n` isn't between 1 and 4.
+    ///
+    /// ```
+    /// use win_msgbox::DefaultButton;
+    ///
+    /// assert_eq!(DefaultButton::from_index(0), None);
+    /// assert_eq!(DefaultButton::from_index(1), Some(DefaultButton::DefaultButton1));
+    /// assert_eq!(DefaultButton::from_index(4), Some(DefaultButton::DefaultButton4));
+    /// assert_eq!(DefaultButton::from_index(5), None);
+    /// ```
+    pub fn from_index(n: u8) -> Option<DefaultButton> {
+        match n {
+            1 => Some(DefaultButton::DefaultButton1),
+            2 => Some(DefaultButton::DefaultButton2),
+            3 => Some(DefaultButton::DefaultButton3),
+            4 => Some(DefaultButton::DefaultButton4),
+            _ => None,
+        }
+    }
+
+    /// The 1-based index of the default button.
+    ///
+    /// ```
+    /// use win_msgbox::DefaultButton;
+    ///
+    /// assert_eq!(DefaultButton::DefaultButton3.index(), 3);
+    /// ```
+    pub fn index(self) -> u8 {
+        match self {
+            DefaultButton::DefaultButton1 => 1,
+            DefaultButton::DefaultButton2 => 2,
+            DefaultButton::DefaultButton3 => 3,
+            DefaultButton::DefaultButton4 => 4,
+        }
+    }
+}
+
+/// The severity of a message, selecting the icon and default button through [`severity`](MessageBox::severity).
+///
+/// | Severity                   | Icon                             | Default button                      |
+/// |----------------------------|----------------------------------|-------------------------------------|
+/// | [Info](Self::Info)         | [Information](Icon::Information) | first                               |
+/// | [Warning](Self::Warning)   | [Warning](Icon::Warning)         | [safe button](Options::safe_button) |
+/// | [Error](Self::Error)       | [Error](Icon::Error)             | [safe button](Options::safe_button) |
+/// | [Question](Self::Question) | [Question](Icon::Question)       | first                               |
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// An informational message.
+    Info,
+    /// A warning about a potential problem.
+    Warning,
+    /// An error.
+    Error,
+    /// A question to the user.
+    Question,
+}
+
+/// The owner window of a message box.
+///
+/// Use [`Owner::none`] for a message box without an owner window (the default),
+/// or convert an `HWND` using [`Owner::from_hwnd`] or [From].
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct Owner(HWND);
+
+impl Owner {
+    /// No owner window.
+    pub fn none() -> Self {
+        Self(std::ptr::null_mut())
+    }
+
+    /// The window identified by `hwnd` owns the message box.
+    /// A null `hwnd` is the same as [`Owner::none`].
+    pub fn from_hwnd(hwnd: HWND) -> Self {
+        Self(hwnd)
+    }
+
+    /// The raw handle of the owner window (null if there's no owner).
+    pub fn hwnd(self) -> HWND {
+        self.0
+    }
+
+    /// Returns `true` if there's no owner window.
+    pub fn is_none(self) -> bool {
+        self.0.is_null()
+    }
+}
+
+impl Default for Owner {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl From<HWND> for Owner {
+    fn from(hwnd: HWND) -> Self {
+        Self::from_hwnd(hwnd)
+    }
+}
+
+/// Converts an `HWND` of the [`windows`](https://docs.rs/windows) crate (enabled by the `windows-interop` feature).
+///
+/// ```
+/// # #[cfg(all(windows, feature = "windows-interop"))]
+/// # {
+/// use win_msgbox::{MessageBox, Okay, Owner};
+/// use windows::Win32::Foundation::HWND;
+///
+/// let window = HWND(1 as _);
+/// assert_eq!(Owner::from(window).hwnd(), 1 as _);
+/// let msgbox = MessageBox::<Okay>::new("Saved").hwnd(window);
+/// # }
+/// ```
+#[cfg(all(windows, feature = "windows-interop"))]
+impl From<windows::Win32::Foundation::HWND> for Owner {
+    fn from(hwnd: windows::Win32::Foundation::HWND) -> Self {
+        Self::from_hwnd(hwnd.0)
+    }
+}
+
 /// A builder for a modal dialog box that contains a system icon,
 /// a set of buttons, and a brief application-specific message, such as status or error information.
 ///
 /// The type of the message box is specified by `T` (See [Options] for available options).
+///
+/// A configured builder can be cloned to show it multiple times:
+///
+/// ```no_run
+/// use win_msgbox::{MessageBox, Okay};
+///
+/// # fn main() -> win_msgbox::Result<()> {
+/// let template = MessageBox::<Okay>::warning("Low disk space").title("Storage");
+/// template.clone().show()?;
+///
+/// assert_eq!(
+///     format!("{template:?}"),
+///     r#"MessageBox { title: Some("Storage"), text: "Low disk space", icon: Some(Warning), hwnd: 0x0 }"#
+/// );
+/// #    Ok(())
+/// # }
+/// ```
+#[cfg(feature = "safe")]
 pub struct MessageBox<'a, T> {
-    /// The icon of this message box.
-    icon: Icon,
+    /// The icon of this message box (`None` if it has [no icon](Self::without_icon)).
+    icon: Option<Icon>,
+    /// The icon replacing the system icon once the message box is created (default is None).
+    custom_icon: Option<CustomIcon>,
+    /// The sound to play before showing the message box (default is None).
+    sound: Option<MessageBeepSound>,
+    /// The path of a `.wav` file to play before showing the message box (default is None).
+    sound_file: Option<&'a str>,
     /// The text inside the message box.
-    text: &'a str,
+    text: Text<'a>,
     /// The title of the message box (default is None).
-    title: Option<&'a str>,
+    title: Option<Text<'a>>,
     /// The owner window of the message box (default is `0` - no owner)
     hwnd: HWND,
+    /// The title of the owner window, which is looked up when the message box is shown (default is None).
+    owner_title: Option<&'a str>,
+    /// The position of the message box in screen coordinates (default is None - centered).
+    position: Option<(i32, i32)>,
+    /// The monitor to center the message box on (default is None - centered on the owner or the screen).
+    monitor: Option<MonitorSelector>,
+    /// Whether to give the focus back to the previous foreground window (default is false).
+    keep_foreground: bool,
+    /// Whether to activate the previous foreground window once the message box is closed (default is false).
+    restore_focus: bool,
+    /// A tag to correlate the response with (default is None).
+    id: Option<u64>,
+    /// Whether to move the message box back to the top periodically (default is false).
+    keep_topmost: bool,
+    /// How long the message box fades in (default is None - shown immediately).
+    fade_in: Option<Duration>,
+    /// How long the buttons are disabled after the message box is shown (default is None).
+    min_display_time: Option<Duration>,
+    /// The language of the buttons (default is None - the language of the system).
+    language: Option<u16>,
+    /// Whether to close the message box when ESC is pressed (default is false).
+    escapable: bool,
+    /// Whether to show the message box with per-monitor DPI awareness (default is false).
+    dpi_aware: bool,
+    /// Whether the message box must be task-modal without an owner (default is false).
+    thread_modal: bool,
+    /// Whether an owner on another thread is an error (default is false).
+    strict_threading: bool,
+    /// Whether to replace lone `\n` and `\r` in the text with `\r\n` (default is false).
+    normalize_newlines: bool,
+    /// Whether to right-align the text and use right-to-left reading order if it's mostly RTL (default is false).
+    auto_direction: bool,
+    /// The callback receiving the message box once it's created (default is None).
+    on_created: Option<OnCreated>,
     /// Flags for the creation of this message box.
     flags: MESSAGEBOX_STYLE,
     /// The response options of message box.
     _response: PhantomData<T>,
 }
 
+#[cfg(feature = "safe")]
 impl<T> std::fmt::Debug for MessageBox<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MessageBox")
@@ -202,103 +1011,855 @@ impl<T> std::fmt::Debug for MessageBox<'_, T> {
     }
 }
 
+/// Compares all options of two message boxes.
+///
+/// [`on_created`](MessageBox::on_created) callbacks are compared by identity,
+/// so they're only equal for clones of the same message box.
+/// Text created through [`text_os`](MessageBox::text_os) never equals text created from a `str`.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use win_msgbox::{MessageBox, Okay};
+///
+/// let mut pending = HashSet::new();
+/// pending.insert(MessageBox::<Okay>::warning("Low disk space").title("Storage"));
+/// pending.insert(MessageBox::<Okay>::warning("Low disk space").title("Storage"));
+/// pending.insert(MessageBox::<Okay>::error("Low disk space").title("Storage"));
+/// pending.insert(MessageBox::<Okay>::error("Low disk space").title("Storage").position(0, 0));
+/// assert_eq!(pending.len(), 3);
+/// ```
+#[cfg(feature = "safe")]
+impl<T> PartialEq for MessageBox<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.icon == other.icon
+            && self.custom_icon == other.custom_icon
+            && self.sound == other.sound
+            && self.sound_file == other.sound_file
+            && self.text == other.text
+            && self.title == other.title
+            && self.hwnd == other.hwnd
+            && self.owner_title == other.owner_title
+            && self.position == other.position
+            && self.monitor == other.monitor
+            && self.keep_foreground == other.keep_foreground
+            && self.restore_focus == other.restore_focus
+            && self.id == other.id
+            && self.keep_topmost == other.keep_topmost
+            && self.fade_in == other.fade_in
+            && self.min_display_time == other.min_display_time
+            && self.language == other.language
+            && self.escapable == other.escapable
+            && self.dpi_aware == other.dpi_aware
+            && self.thread_modal == other.thread_modal
+            && self.strict_threading == other.strict_threading
+            && self.normalize_newlines == other.normalize_newlines
+            && self.auto_direction == other.auto_direction
+            && self.flags == other.flags
+            && self.on_created_ptr() == other.on_created_ptr()
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<T> Eq for MessageBox<'_, T> {}
+
+/// Hashes the same fields that are compared by [PartialEq].
+#[cfg(feature = "safe")]
+impl<T> std::hash::Hash for MessageBox<'_, T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.icon.hash(state);
+        self.custom_icon.hash(state);
+        self.sound.hash(state);
+        self.sound_file.hash(state);
+        self.text.hash(state);
+        self.title.hash(state);
+        self.hwnd.hash(state);
+        self.owner_title.hash(state);
+        self.position.hash(state);
+        self.monitor.hash(state);
+        self.keep_foreground.hash(state);
+        self.restore_focus.hash(state);
+        self.id.hash(state);
+        self.keep_topmost.hash(state);
+        self.fade_in.hash(state);
+        self.min_display_time.hash(state);
+        self.language.hash(state);
+        self.escapable.hash(state);
+        self.dpi_aware.hash(state);
+        self.thread_modal.hash(state);
+        self.strict_threading.hash(state);
+        self.normalize_newlines.hash(state);
+        self.auto_direction.hash(state);
+        self.flags.hash(state);
+        self.on_created_ptr().hash(state);
+    }
+}
+
+/// Creates a message box with an empty text, same as [`MessageBox::new("")`](MessageBox::new).
+///
+/// ```
+/// use win_msgbox::{MessageBox, Okay};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{MB_ICONINFORMATION, MB_OK, MB_TOPMOST};
+///
+/// let msgbox = MessageBox::<Okay>::default().title("Status").topmost();
+/// assert_eq!(msgbox.style(), MB_OK | MB_ICONINFORMATION | MB_TOPMOST);
+/// ```
+#[cfg(feature = "safe")]
+impl<T> Default for MessageBox<'_, T> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<T> Clone for MessageBox<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            icon: self.icon,
+            custom_icon: self.custom_icon,
+            sound: self.sound,
+            sound_file: self.sound_file,
+            text: self.text.clone(),
+            title: self.title.clone(),
+            hwnd: self.hwnd,
+            owner_title: self.owner_title,
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            restore_focus: self.restore_focus,
+            escapable: self.escapable,
+            dpi_aware: self.dpi_aware,
+            thread_modal: self.thread_modal,
+            strict_threading: self.strict_threading,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            language: self.language,
+            id: self.id,
+            normalize_newlines: self.normalize_newlines,
+            auto_direction: self.auto_direction,
+            on_created: self.on_created.clone(),
+            flags: self.flags,
+            _response: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "safe")]
 macro_rules! ctors {
     ($($name:ident => $icon:ident),*) => {
         impl <'a, T> MessageBox<'a, T> {
             $(
             #[doc = concat!("Creates a new message box where its icon is set to [", stringify!($icon), "](Icon::", stringify!($icon),").")]
-            pub fn $name(text: &'a str) -> Self {
+            pub fn $name(text: impl Into<Cow<'a, str>>) -> Self {
                 Self::new(text).icon(Icon::$icon)
             }
             )*
         }
         $(
         #[doc = concat!("Creates a new message box where its icon is set to [", stringify!($icon), "](Icon::", stringify!($icon),").")]
-        pub fn $name<T>(text: &str) -> MessageBox<'_, T> {
+        pub fn $name<'a, T>(text: impl Into<Cow<'a, str>>) -> MessageBox<'a, T> {
             MessageBox::<T>::$name(text)
         })*
     };
 }
 
+#[cfg(feature = "safe")]
 impl<'a, T> MessageBox<'a, T> {
     /// Creates a new message box with a specified `text` to be displayed.
     /// If the string consists of more than one line,
     /// you can separate the lines using a carriage return and/or linefeed character between each line.
-    pub fn new(text: &'a str) -> Self {
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            icon: Icon::Information,
-            text,
+            icon: Some(Icon::Information),
+            custom_icon: None,
+            sound: None,
+            sound_file: None,
+            text: Text::Str(text.into()),
             title: None,
             hwnd: std::ptr::null_mut(),
+            owner_title: None,
+            position: None,
+            monitor: None,
+            keep_foreground: false,
+            restore_focus: false,
+            escapable: false,
+            dpi_aware: false,
+            thread_modal: false,
+            strict_threading: false,
+            keep_topmost: false,
+            fade_in: None,
+            min_display_time: None,
+            language: None,
+            id: None,
+            normalize_newlines: false,
+            auto_direction: false,
+            on_created: None,
             flags: 0,
             _response: PhantomData,
         }
     }
 
-    /// The [Icon] to be displayed in this message box.
-    pub fn icon(mut self, icon: Icon) -> Self {
-        self.icon = icon;
-        self
+    /// Creates a new message box where the text consists of `lines`,
+    /// separated by a carriage return and linefeed (`\r\n`).
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let msgbox = MessageBox::<Okay>::lines(&["Download failed.", "Check your connection."]);
+    /// assert!(format!("{msgbox:?}").contains(r#"text: "Download failed.\r\nCheck your connection.""#));
+    ///
+    /// let msgbox = MessageBox::<Okay>::lines(&[]);
+    /// assert!(format!("{msgbox:?}").contains(r#"text: """#));
+    /// ```
+    pub fn lines(lines: &[&str]) -> Self {
+        Self::new(lines.join("\r\n"))
     }
 
-    /// The dialog box title. If this parameter is **null**, the default title is **Error**.
-    pub fn title(mut self, title: &'a str) -> Self {
-        self.title = title.into();
+    /// Replaces lone linefeeds (`\n`) and carriage returns (`\r`) in the text with `\r\n` when the message box is [shown](MessageBox::show),
+    /// since Windows doesn't render them consistently. Existing `\r\n` sequences are kept.
+    ///
+    /// See [`win_msgbox::normalize_newlines`](crate::normalize_newlines) for the conversion.
+    #[must_use]
+    pub fn normalize_newlines(mut self) -> Self {
+        self.normalize_newlines = true;
         self
     }
 
-    /// A handle to the owner window of the message box to be created.
-    /// If this parameter is `0`, the message box has no owner window (default).
-    pub fn hwnd(mut self, hwnd: HWND) -> Self {
-        self.hwnd = hwnd;
-        self
+    /// Creates a new message box with a specified `text` and `title`.
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let msgbox = MessageBox::<Okay>::captioned("Ferris landed on Mars.", "Landing Module");
+    /// assert!(format!("{msgbox:?}").contains(r#"title: Some("Landing Module")"#));
+    /// ```
+    pub fn captioned(text: impl Into<Cow<'a, str>>, title: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(text).title(title)
     }
 
-    /// Set the modality of the dialog box. See [Modal] for options.
-    pub fn modal(mut self, modal: Modal) -> Self {
-        self.flags |= modal as u32;
+    /// The [Icon] to be displayed in this message box.
+    #[must_use]
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
         self
     }
 
-    /// Set the default button of the dialog box. See [DefaultButton] for options.
-    pub fn default_button(mut self, btn: DefaultButton) -> Self {
-        self.flags |= btn as u32;
+    /// Shows the message box without an icon (the default is [Information](Icon::Information)).
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::MB_OK;
+    ///
+    /// let msgbox = MessageBox::<Okay>::new("Hello").without_icon();
+    /// assert_eq!(msgbox.style(), MB_OK);
+    /// ```
+    #[must_use]
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self.custom_icon = None;
         self
     }
 
-    /// Same as desktop of the interactive window station. For more information, see [Window Stations](https://learn.microsoft.com/windows/desktop/winstation/window-stations).
-    /// If the current input desktop is not the default desktop,
-    /// [show](Self::show) does not return until the user switches to the default desktop.
-    pub fn default_desktop_only(mut self) -> Self {
-        self.flags |= MB_DEFAULT_DESKTOP_ONLY;
+    /// Shows `icon` instead of the system icon, e.g. the icon of the application.
+    ///
+    /// The custom icon takes precedence over the [icon](Self::icon), which only selects the sound
+    /// (if the message box has [no icon](Self::without_icon), the sound of [Information](Icon::Information) is used).
+    /// Win32 can only show custom icons from resources of a module, so the icon is set
+    /// through a `WH_CBT` hook (like [`position`](Self::position)) once the message box is created.
+    ///
+    /// See the `custom_icon` example for loading an icon from a `.ico` file.
+    ///
+    /// ```
+    /// use win_msgbox::{CustomIcon, Icon, MessageBox, Okay};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{MB_ICONINFORMATION, MB_ICONWARNING};
+    ///
+    /// # let hicon = std::ptr::null_mut();
+    /// let msgbox = MessageBox::<Okay>::new("Synced").icon(Icon::Warning);
+    /// assert_eq!(msgbox.icon_handle(CustomIcon(hicon)).style(), MB_ICONWARNING);
+    ///
+    /// let msgbox = MessageBox::<Okay>::new("Synced").without_icon();
+    /// assert_eq!(msgbox.icon_handle(CustomIcon(hicon)).style(), MB_ICONINFORMATION);
+    /// ```
+    #[must_use]
+    pub fn icon_handle(mut self, icon: CustomIcon) -> Self {
+        self.custom_icon = Some(icon);
         self
     }
 
-    /// The text is right-justified.
-    pub fn right(mut self) -> Self {
-        self.flags |= MB_RIGHT;
+    /// Shows the message box without an icon, same as [`without_icon`](Self::without_icon).
+    ///
+    /// Win32 couples the sound of a message box to its icon,
+    /// so removing the icon also stops the system from playing a sound.
+    /// Use [`sound`](Self::sound) to play a sound regardless.
+    #[must_use]
+    pub fn silent(self) -> Self {
+        self.without_icon()
+    }
+
+    /// Plays a [MessageBeepSound] right before the message box is shown.
+    ///
+    /// This is independent of the [Icon] - if the message box has an icon,
+    /// the system will play the icon's sound in addition to this one.
+    /// Combine this with [`silent`](Self::silent) to only play this sound.
+    #[must_use]
+    pub fn sound(mut self, sound: MessageBeepSound) -> Self {
+        self.sound = Some(sound);
+        self
+    }
+
+    /// Sets the [Sound] played when the message box is shown.
+    ///
+    /// - [`Sound::Default`] keeps the sound of the [Icon] and removes a custom sound.
+    /// - [`Sound::None`] is the same as [`silent`](Self::silent), so it removes the icon.
+    ///   Setting an [icon](Self::icon) afterwards brings back its sound.
+    /// - [`Sound::Custom`] plays a `.wav` file. The system plays the sound of the icon as well,
+    ///   which interrupts the custom sound, so combine this with [`silent`](Self::silent)
+    ///   unless the icon is needed. Files that can't be played are ignored.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, Okay, Sound};
+    ///
+    /// MessageBox::<Okay>::new("The build finished")
+    ///     .silent()
+    ///     .with_sound(Sound::Custom(r"C:\Windows\Media\tada.wav"))
+    ///     .show()?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    #[must_use]
+    pub fn with_sound(mut self, sound: Sound<'a>) -> Self {
+        match sound {
+            Sound::Default => {
+                self.sound_file = None;
+                self
+            }
+            Sound::None => {
+                self.sound_file = None;
+                self.silent()
+            }
+            Sound::Custom(path) => {
+                self.sound_file = Some(path);
+                self
+            }
+        }
+    }
+
+    /// The dialog box title. If this parameter is **null**, the default title is **Error**.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(Text::Str(title.into()));
+        self
+    }
+
+    /// Sets the title, truncating it to at most `max_chars` UTF-16 code units.
+    ///
+    /// Windows cuts long titles off at the edge of the caption bar without any indication.
+    /// If the title is too long, it's cut before a character (never between the two halves of a surrogate pair),
+    /// and an ellipsis (`…`) is appended, which counts towards `max_chars`.
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let title = |max| format!("{:?}", MessageBox::<Okay>::new("").title_truncated("Hi 😀😀", max));
+    /// assert!(title(7).contains(r#"title: Some("Hi 😀😀")"#));
+    /// assert!(title(6).contains(r#"title: Some("Hi 😀…")"#));
+    /// // The emoji takes two code units, so it doesn't fit with the ellipsis.
+    /// assert!(title(5).contains(r#"title: Some("Hi …")"#));
+    /// ```
+    #[must_use]
+    pub fn title_truncated(self, title: &'a str, max_chars: usize) -> Self {
+        if title.encode_utf16().count() <= max_chars {
+            return self.title(title);
+        }
+        let budget = max_chars.saturating_sub(1);
+        let mut len = 0;
+        let end = title
+            .char_indices()
+            .find(|(_, c)| {
+                len += c.len_utf16();
+                len > budget
+            })
+            .map_or(title.len(), |(i, _)| i);
+        let mut truncated = title[..end].to_owned();
+        if max_chars > 0 {
+            truncated.push('…');
+        }
+        self.title(truncated)
+    }
+
+    /// Sets the title to `base` followed by `count` in parentheses, e.g. for a message box summarizing multiple errors.
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let msgbox = MessageBox::<Okay>::error("Some files couldn't be saved.").title_with_count("Errors", 3);
+    /// assert!(format!("{msgbox:?}").contains(r#"title: Some("Errors (3)")"#));
+    /// ```
+    #[must_use]
+    pub fn title_with_count(self, base: &str, count: usize) -> Self {
+        self.title(format!("{base} ({count})"))
+    }
+
+    /// Sets the title from an OS string, such as a file name or [Path](std::path::Path).
+    ///
+    /// On Windows, the string is encoded to UTF-16 directly,
+    /// so names that aren't valid Unicode are preserved.
+    ///
+    /// ```
+    /// # #[cfg(windows)]
+    /// # {
+    /// use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// // "file" followed by an unpaired surrogate
+    /// let name = OsString::from_wide(&[0x66, 0x69, 0x6C, 0x65, 0xD800]);
+    /// let msgbox = MessageBox::<YesNo>::question("Delete this file?").title_os(&name);
+    /// assert!(format!("{msgbox:?}").contains(r#"title: Some("file\u{d800}")"#));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn title_os<S: AsRef<OsStr> + ?Sized>(mut self, title: &'a S) -> Self {
+        self.title = Some(Text::Os(title.as_ref()));
+        self
+    }
+
+    /// Sets the title to the name of the current executable.
+    ///
+    /// The name is looked up once per process, trying the following in order:
+    ///
+    /// 1. The `FileDescription` in the version info of the executable (the name shown in the Task Manager).
+    /// 2. The file name of the executable without its extension (e.g. `setup` for `C:\Tools\setup.exe`).
+    ///
+    /// If neither is available, the title is left unchanged.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// MessageBox::<Okay>::information("The update was installed.")
+    ///     .title_from_exe()
+    ///     .show()?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    #[must_use]
+    pub fn title_from_exe(mut self) -> Self {
+        if let Some(title) = exe::exe_title() {
+            self.title = Some(Text::Str(Cow::Borrowed(title)));
+        }
+        self
+    }
+
+    /// Sets the text from an OS string, such as a file name or [Path](std::path::Path).
+    ///
+    /// On Windows, the string is encoded to UTF-16 directly,
+    /// so names that aren't valid Unicode are preserved.
+    #[must_use]
+    pub fn text_os<S: AsRef<OsStr> + ?Sized>(mut self, text: &'a S) -> Self {
+        self.text = Text::Os(text.as_ref());
+        self
+    }
+
+    /// Sets the text, wrapping it at spaces so lines are at most `width_chars` characters long.
+    ///
+    /// Windows wraps the text at a width based on the screen size, which makes for long lines on large monitors.
+    /// Existing newlines are kept (as `\r\n`), and words longer than `width_chars` get a line of their own.
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let text = "The download couldn't be completed because the server closed the connection. \
+    ///             Check your network settings and try again.";
+    /// let msgbox = MessageBox::<Okay>::new("").text_wrapped(text, 30);
+    /// assert_eq!(msgbox.summary(usize::MAX), "The download couldn't be");
+    ///
+    /// // All lines are broken at spaces
+    /// let debug = format!("{msgbox:?}");
+    /// let wrapped = debug.split(r#"text: ""#).nth(1).unwrap().split('"').next().unwrap();
+    /// let lines: Vec<_> = wrapped.split(r"\r\n").collect();
+    /// assert!(lines.iter().all(|line| line.chars().count() <= 30));
+    /// assert_eq!(lines.join(" "), text);
+    ///
+    /// let msgbox = MessageBox::<Okay>::new("").text_wrapped("Retry later?\nThe server is busy", 10);
+    /// assert!(format!("{msgbox:?}").contains(r#"text: "Retry\r\nlater?\r\nThe server\r\nis busy""#));
+    /// ```
+    #[must_use]
+    pub fn text_wrapped(mut self, text: &str, width_chars: usize) -> Self {
+        self.text = Text::Str(text::wrap(text, width_chars).into());
+        self
+    }
+
+    /// The [Owner] window of the message box to be created.
+    /// If this is [`Owner::none`], the message box has no owner window (default).
+    ///
+    /// An owner can't be combined with [`service_notification`](Self::service_notification)
+    /// or [`task_modal_current_thread`](Self::task_modal_current_thread).
+    #[must_use]
+    pub fn hwnd(mut self, hwnd: impl Into<Owner>) -> Self {
+        self.hwnd = hwnd.into().hwnd();
+        self.owner_title = None;
+        self
+    }
+
+    /// Uses the top-level window with the title `title` as the [owner](Self::hwnd).
+    ///
+    /// The window is looked up through [FindWindowW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-findwindoww)
+    /// when the message box is [shown](Self::show). If there's no such window, the message box has no owner.
+    /// If the lookup fails, [show](Self::show) returns the error.
+    ///
+    /// Titles aren't unique and can change at any time, so the window might be a different one than intended,
+    /// or it might be closed between the lookup and showing the message box.
+    /// Prefer [hwnd](Self::hwnd) if the handle is available.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// MessageBox::<Okay>::new("The script finished.")
+    ///     .owner_by_title("Untitled - Notepad")
+    ///     .show()?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    #[must_use]
+    pub fn owner_by_title(mut self, title: &'a str) -> Self {
+        self.hwnd = std::ptr::null_mut();
+        self.owner_title = Some(title);
+        self
+    }
+
+    /// The owner window, looking it up by its [title](Self::owner_by_title) if requested.
+    fn owner(&self) -> Result<HWND> {
+        let Some(title) = self.owner_title else {
+            return Ok(self.hwnd);
+        };
+        let title: Vec<u16> = title.encode_utf16().chain(std::iter::once(0)).collect();
+        unsafe {
+            // `FindWindowW` doesn't reset the last error if no window is found.
+            SetLastError(0);
+            let hwnd = FindWindowW(std::ptr::null(), title.as_ptr());
+            match GetLastError() {
+                0 => Ok(hwnd),
+                error => Err(error.into()),
+            }
+        }
+    }
+
+    /// Moves the message box to `x` and `y` (in screen coordinates) once it's shown.
+    ///
+    /// By default, the message box is centered on its owner or the screen.
+    /// Windows has no option to position a message box, so while it's shown,
+    /// a `WH_CBT` hook is installed on the current thread to move it once it's activated.
+    /// This relies on hooking and may be fragile across Windows versions.
+    #[must_use]
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Centers the message box on the selected monitor once it's shown,
+    /// e.g. to control where notifications without an owner appear.
+    ///
+    /// Like [`position`](Self::position), this installs a `WH_CBT` hook on the current thread
+    /// which moves the message box into the work area of the monitor once it's activated.
+    /// If the monitor doesn't exist, the message box isn't moved.
+    /// This is ignored if a [`position`](Self::position) is set.
+    #[must_use]
+    pub fn monitor(mut self, monitor: MonitorSelector) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Centers the message box on the monitor containing the cursor,
+    /// same as [`monitor(MonitorSelector::WithCursor)`](Self::monitor).
+    ///
+    /// Without an owner, message boxes are centered on the primary monitor,
+    /// which might not be the one the user is working on.
+    #[must_use]
+    pub fn center_on_screen(self) -> Self {
+        self.monitor(MonitorSelector::WithCursor)
+    }
+
+    /// Tries to keep the focus on the current foreground window instead of moving it to the message box,
+    /// e.g. for notifications shown from the background.
+    ///
+    /// Windows has no flag for this, so it's approximated: the foreground window is saved before the message box is shown,
+    /// and a `WH_CBT` hook gives it back the focus once the message box is activated.
+    /// The message box still flashes into the foreground briefly, and Windows may refuse to change the
+    /// foreground window (see [SetForegroundWindow](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-setforegroundwindow)).
+    #[must_use]
+    pub fn no_steal_focus(mut self) -> Self {
+        self.keep_foreground = true;
+        self
+    }
+
+    /// Gives the focus back to the previous foreground window once the message box is closed,
+    /// e.g. for message boxes shown by tray or background applications.
+    ///
+    /// When a message box with an [owner](Self::hwnd) is closed, Windows activates the owner again.
+    /// Without an owner, the focus sometimes doesn't return to the window the user was working in,
+    /// so their keyboard input is lost. With this, the foreground window is saved before the message box is shown
+    /// and brought back to the foreground afterwards (if it still exists).
+    ///
+    /// This only applies to message boxes without an owner. Windows may refuse to change the foreground window
+    /// (see [SetForegroundWindow](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-setforegroundwindow)).
+    #[must_use]
+    pub fn restore_focus(mut self) -> Self {
+        self.restore_focus = true;
+        self
+    }
+
+    /// Closes the message box when ESC is pressed, even if it has no **Cancel** button.
+    ///
+    /// The message box returns `IDCANCEL`, which each option maps to:
+    ///
+    /// - [AbortRetryIgnore] → [`Ignore`](AbortRetryIgnore::Ignore)
+    /// - [CancelTryAgainContinue] → [`Cancel`](CancelTryAgainContinue::Cancel)
+    /// - [Okay] → [Okay]
+    /// - [OkayCancel] → [`Cancel`](OkayCancel::Cancel)
+    /// - [RetryCancel] → [`Cancel`](RetryCancel::Cancel)
+    /// - [YesNo] → [`No`](YesNo::No)
+    /// - [YesNoCancel] → [`Cancel`](YesNoCancel::Cancel)
+    ///
+    /// While the message box is shown, a `WH_KEYBOARD` hook is installed on the current thread.
+    #[must_use]
+    pub fn escapable(mut self) -> Self {
+        self.escapable = true;
+        self
+    }
+
+    /// Calls `callback` with the window of the message box once it's created,
+    /// e.g. to subclass it or add controls.
+    ///
+    /// Like [`position`](Self::position), this installs a `WH_CBT` hook on the current thread while the message box is shown.
+    /// The callback runs on the same thread once the message box is activated for the first time,
+    /// before it waits for input.
+    /// The window is provided as-is: modifying the message box is not supported by Windows
+    /// and may break in future versions.
+    ///
+    /// A panic in the callback can't unwind through the hook, so it's caught and the message box is shown regardless.
+    /// The callback isn't called again afterwards (including by clones of this message box).
+    #[must_use]
+    pub fn on_created(mut self, callback: impl FnMut(HWND) + Send + 'static) -> Self {
+        self.on_created = Some(Arc::new(Mutex::new(callback)));
+        self
+    }
+
+    /// Calls `callback` after the callback set through [`on_created`](Self::on_created) (if any).
+    fn also_on_created(self, mut callback: impl FnMut(HWND) + Send + 'static) -> Self {
+        let on_created = self.on_created.clone();
+        self.on_created(move |hwnd| {
+            if let Some(on_created) = &on_created {
+                if let Ok(mut on_created) = on_created.lock() {
+                    on_created(hwnd);
+                }
+            }
+            callback(hwnd);
+        })
+    }
+
+    /// Tags the message box with an opaque `id` to correlate its response, e.g. when spawning many message boxes.
+    ///
+    /// The id isn't passed to Windows. It's returned alongside the response by
+    /// [`spawn_cancellable`](Self::spawn_cancellable).
+    #[must_use]
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the modality of the dialog box. See [Modal] for options.
+    #[must_use]
+    pub fn modal(mut self, modal: Modal) -> Self {
+        self.flags |= modal as u32;
+        self
+    }
+
+    /// Makes the message box [task-modal](Modal::Task) without an owner,
+    /// so all top-level windows of the current thread are disabled while it's shown.
+    ///
+    /// Windows only disables the windows of the thread if there's no owner,
+    /// so [show](Self::show) returns `ERROR_INVALID_PARAMETER` if an [owner](Self::hwnd) is set.
+    #[must_use]
+    pub fn task_modal_current_thread(mut self) -> Self {
+        self.thread_modal = true;
+        self.modal(Modal::Task)
+    }
+
+    /// Makes [show](Self::show) return [`MsgBoxError::CrossThreadOwner`] if the [owner](Self::hwnd)
+    /// belongs to another thread.
+    ///
+    /// A modal message box disables its owner, which involves the thread of the owner.
+    /// If that thread is blocked (e.g. because it waits for the current thread), showing the message box hangs.
+    /// Without this, such an owner is only reported as a warning in debug builds
+    /// (through `tracing` with the `tracing` feature, otherwise on stderr).
+    #[must_use]
+    pub fn strict_threading(mut self) -> Self {
+        self.strict_threading = true;
+        self
+    }
+
+    /// Set the default button of the dialog box. See [DefaultButton] for options.
+    #[must_use]
+    pub fn default_button(mut self, btn: DefaultButton) -> Self {
+        self.flags |= btn as u32;
+        self
+    }
+
+    /// Same as desktop of the interactive window station. For more information, see [Window Stations](https://learn.microsoft.com/windows/desktop/winstation/window-stations).
+    /// If the current input desktop is not the default desktop,
+    /// [show](Self::show) does not return until the user switches to the default desktop.
+    #[must_use]
+    pub fn default_desktop_only(mut self) -> Self {
+        self.flags |= MB_DEFAULT_DESKTOP_ONLY;
+        self
+    }
+
+    /// The text is right-justified.
+    #[must_use]
+    pub fn right(mut self) -> Self {
+        self.flags |= MB_RIGHT;
         self
     }
 
     /// Displays message and caption text using right-to-left reading order on Hebrew and Arabic systems.
+    #[must_use]
     pub fn rtl_reading(mut self) -> Self {
         self.flags |= MB_RTLREADING;
         self
     }
 
+    /// Sets [right](Self::right) and [rtl_reading](Self::rtl_reading) if the text is predominantly right-to-left.
+    ///
+    /// The heuristic is conservative: it only counts letters and requires more Hebrew or Arabic letters
+    /// than letters of other scripts. Digits, punctuation, and whitespace are ignored.
+    /// The title isn't considered.
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{MB_RIGHT, MB_RTLREADING};
+    ///
+    /// let arabic = MessageBox::<Okay>::new("تم حفظ الملف بنجاح").auto_direction();
+    /// assert_eq!(arabic.style() & (MB_RIGHT | MB_RTLREADING), MB_RIGHT | MB_RTLREADING);
+    ///
+    /// let hebrew = MessageBox::<Okay>::new("הקובץ נשמר (file.txt)").auto_direction();
+    /// assert_eq!(hebrew.style() & (MB_RIGHT | MB_RTLREADING), MB_RIGHT | MB_RTLREADING);
+    ///
+    /// let latin = MessageBox::<Okay>::new("The file was saved (תם)").auto_direction();
+    /// assert_eq!(latin.style() & (MB_RIGHT | MB_RTLREADING), 0);
+    /// ```
+    #[must_use]
+    pub fn auto_direction(mut self) -> Self {
+        self.auto_direction = true;
+        self
+    }
+
     /// The message box becomes the foreground window.
     /// Internally, the system calls the [SetForegroundWindow](https://learn.microsoft.com/windows/desktop/api/winuser/nf-winuser-setforegroundwindow) function for the message box.
+    #[must_use]
     pub fn set_foreground(mut self) -> Self {
         self.flags |= MB_SETFOREGROUND;
         self
     }
 
     /// The message box is created with the `WS_EX_TOPMOST` window style.
+    #[must_use]
     pub fn topmost(mut self) -> Self {
         self.flags |= MB_TOPMOST;
         self
     }
 
+    /// Like [`topmost`](Self::topmost), but keeps the message box above other topmost windows,
+    /// e.g. for alerts in kiosk applications.
+    ///
+    /// Some shells and applications move their windows above a topmost message box.
+    /// To counter this, a timer moves the message box back to the top every 250 milliseconds while it's shown.
+    /// The timer is set up through a `WH_CBT` hook (like [`position`](Self::position))
+    /// and stops when the message box is closed. Its CPU cost is negligible, but it wakes up the thread regularly.
+    #[must_use]
+    pub fn keep_topmost(mut self) -> Self {
+        self.keep_topmost = true;
+        self.topmost()
+    }
+
+    /// Shows the message box with per-monitor DPI awareness (V2), so it's rendered crisply on high-DPI monitors,
+    /// even if the process isn't DPI aware.
+    ///
+    /// The DPI awareness of the current thread is changed through
+    /// [SetThreadDpiAwarenessContext](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-setthreaddpiawarenesscontext)
+    /// while the message box is shown, and the previous awareness is restored once it's closed (even if showing it failed).
+    /// Windows of the thread that are created while the message box is shown (e.g. in [`on_created`](Self::on_created))
+    /// are DPI aware as well.
+    ///
+    /// This requires Windows 10, version 1703 or later. On older versions, it has no effect.
+    #[must_use]
+    pub fn dpi_aware(mut self) -> Self {
+        self.dpi_aware = true;
+        self
+    }
+
+    /// Fades the message box in over `duration`, e.g. for subtle notifications.
+    ///
+    /// The message box is made transparent through the `WS_EX_LAYERED` style once it's created,
+    /// and a timer raises its opacity until it's fully opaque after `duration`.
+    /// Then, the style is removed again. The message box accepts input while it fades in.
+    ///
+    /// This is purely cosmetic and set up through a `WH_CBT` hook (like [`position`](Self::position)).
+    /// If the hook runs after the message box was drawn for the first time, it might flicker briefly.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// MessageBox::<Okay>::information("Backup completed")
+    ///     .fade_in(Duration::from_millis(300))
+    ///     .show()?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    #[must_use]
+    pub fn fade_in(mut self, duration: Duration) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+
+    /// Disables the buttons for `duration` after the message box is shown, so critical alerts aren't
+    /// dismissed by accident (e.g. by a pending click or key press) before they're read.
+    ///
+    /// The buttons are disabled through a `WH_CBT` hook (like [`position`](Self::position))
+    /// and enabled again by a timer. This is a soft guard: the message box can still be closed
+    /// through the keyboard (e.g. ESC or Alt+F4) if it has a **Cancel** or **OK** button
+    /// or is [escapable](Self::escapable), as these don't go through the buttons.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// MessageBox::<Okay>::error("The reactor is overheating.")
+    ///     .min_display_time(Duration::from_secs(3))
+    ///     .show()?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    #[must_use]
+    pub fn min_display_time(mut self, duration: Duration) -> Self {
+        self.min_display_time = Some(duration);
+        self
+    }
+
+    /// Shows the text of the buttons in the language `language` through
+    /// [MessageBoxExW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxexw).
+    ///
+    /// `language` is a `LANGID` as created by `MAKELANGID(primary, sublanguage)`.
+    /// `0` uses the default language of the system. Other languages require the corresponding language pack.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// // MAKELANGID(LANG_GERMAN, SUBLANG_GERMAN)
+    /// const GERMAN: u16 = 0x0407;
+    /// let response = MessageBox::<YesNo>::new("Datei löschen?").language(GERMAN).show()?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    #[must_use]
+    pub fn language(mut self, language: u16) -> Self {
+        self.language = Some(language);
+        self
+    }
+
     /// The caller is a service notifying the user of an event.
     /// The function displays a message box on the current active desktop,
     /// even if there is no user logged on to the computer.
@@ -306,12 +1867,14 @@ impl<'a, T> MessageBox<'a, T> {
     /// Terminal Services: If the calling thread has an impersonation token,
     /// the function directs the message box to the session specified in the impersonation token.
     ///
-    /// If this is called, [`hwnd`](Self::hwnd) must not be called - it must remain `0`.
-    /// his is so that the message box can appear on a desktop other than the desktop corresponding to the `hwnd`.
+    /// If this is called, [`hwnd`](Self::hwnd) must not be called - it must remain [`Owner::none`],
+    /// otherwise [show](Self::show) returns `ERROR_INVALID_PARAMETER`.
+    /// This is so that the message box can appear on a desktop other than the desktop corresponding to the `hwnd`.
     ///
     /// For information on security considerations in regard to using this flag, see [Interactive Services](https://learn.microsoft.com/windows/desktop/Services/interactive-services).
     /// In particular, be aware that this flag can produce interactive content on a locked desktop
     /// and should therefore be used for only a very limited set of scenarios, such as resource exhaustion.
+    #[must_use]
     pub fn service_notification(mut self) -> Self {
         self.flags |= MB_SERVICE_NOTIFICATION;
         self
@@ -320,13 +1883,253 @@ impl<'a, T> MessageBox<'a, T> {
     /// Adds a Help button to the message box.
     /// When the user clicks the Help button or presses F1,
     /// the system sends a [WM_HELP](https://learn.microsoft.com/windows/desktop/shell/wm-help) message to the owner.
+    #[must_use]
     pub fn with_help(mut self) -> Self {
         self.flags |= MB_HELP;
         self
     }
+
+    /// Adds raw `flags` to the style of the message box.
+    /// See [flags] for the available modifiers.
+    ///
+    /// The buttons are controlled by `T` and the icon by [`icon`](Self::icon).
+    /// Passing flags for either is the caller's responsibility and will likely produce an unexpected message box.
+    #[must_use]
+    pub fn with_flags(mut self, flags: MESSAGEBOX_STYLE) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Changes the buttons of the message box to `U` (See [Options] for available options),
+    /// keeping all other configuration.
+    #[must_use]
+    pub fn with_options<U>(self) -> MessageBox<'a, U> {
+        MessageBox {
+            icon: self.icon,
+            custom_icon: self.custom_icon,
+            sound: self.sound,
+            sound_file: self.sound_file,
+            text: self.text,
+            title: self.title,
+            hwnd: self.hwnd,
+            owner_title: self.owner_title,
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            restore_focus: self.restore_focus,
+            escapable: self.escapable,
+            dpi_aware: self.dpi_aware,
+            thread_modal: self.thread_modal,
+            strict_threading: self.strict_threading,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            language: self.language,
+            id: self.id,
+            normalize_newlines: self.normalize_newlines,
+            auto_direction: self.auto_direction,
+            on_created: self.on_created,
+            flags: self.flags,
+            _response: PhantomData,
+        }
+    }
+
+    /// The flags set by [auto_direction](Self::auto_direction).
+    fn direction_flags(&self) -> MESSAGEBOX_STYLE {
+        if self.auto_direction && self.text.is_rtl() {
+            MB_RIGHT | MB_RTLREADING
+        } else {
+            0
+        }
+    }
+
+    /// The address of the [`on_created`](Self::on_created) callback, which identifies it.
+    fn on_created_ptr(&self) -> Option<*const ()> {
+        self.on_created
+            .as_ref()
+            .map(|callback| Arc::as_ptr(callback).cast::<()>())
+    }
 }
 
+#[cfg(feature = "safe")]
+impl MessageBox<'static, Okay> {
+    /// Creates a new message box showing `err`.
+    ///
+    /// The icon is set to [Error](Icon::Error), the title to **Error**,
+    /// and the text to the [Display](std::fmt::Display) output of `err`.
+    pub fn from_error(err: &dyn std::error::Error) -> Self {
+        Self::error(err.to_string()).title("Error")
+    }
+}
+
+#[cfg(feature = "safe")]
 impl<T: Options> MessageBox<'_, T> {
+    /// Sets the icon and default button based on the [Severity] of the message.
+    ///
+    /// Warnings and errors default to the [safe button](Options::safe_button) (e.g. **No** or **Cancel**),
+    /// so the user doesn't accidentally confirm them.
+    /// A [default button](Self::default_button) set before is replaced.
+    ///
+    /// ```
+    /// use win_msgbox::{DefaultButton, MessageBox, Severity, YesNo, YesNoCancel};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{
+    ///     MB_DEFBUTTON2, MB_DEFBUTTON3, MB_ICONERROR, MB_ICONINFORMATION, MB_YESNO, MB_YESNOCANCEL,
+    /// };
+    ///
+    /// let msgbox = MessageBox::<YesNo>::new("Delete all files?").severity(Severity::Error);
+    /// assert_eq!(msgbox.style(), MB_YESNO | MB_ICONERROR | MB_DEFBUTTON2);
+    ///
+    /// let msgbox = MessageBox::<YesNoCancel>::new("Save changes?")
+    ///     .default_button(DefaultButton::DefaultButton2)
+    ///     .severity(Severity::Error);
+    /// assert_eq!(msgbox.style(), MB_YESNOCANCEL | MB_ICONERROR | MB_DEFBUTTON3);
+    /// assert!(msgbox.validate().is_ok());
+    ///
+    /// let msgbox = MessageBox::<YesNoCancel>::new("Saved.")
+    ///     .default_button(DefaultButton::DefaultButton2)
+    ///     .severity(Severity::Info);
+    /// assert_eq!(msgbox.style(), MB_YESNOCANCEL | MB_ICONINFORMATION);
+    /// ```
+    #[must_use]
+    pub fn severity(mut self, level: Severity) -> Self {
+        let (icon, default_button) = match level {
+            Severity::Info => (Icon::Information, DefaultButton::DefaultButton1),
+            Severity::Warning => (Icon::Warning, T::safe_button()),
+            Severity::Error => (Icon::Error, T::safe_button()),
+            Severity::Question => (Icon::Question, DefaultButton::DefaultButton1),
+        };
+        self.flags &= !MB_DEFMASK;
+        self.icon(icon).default_button(default_button)
+    }
+
+    /// The captions of the buttons implied by `T` in order, e.g. to assert the labels in accessibility tests.
+    ///
+    /// The captions are taken from [`Options::captions`], so they're in English and don't include
+    /// the Help button added by [`with_help`](Self::with_help).
+    ///
+    /// ```
+    /// use win_msgbox::{CancelTryAgainContinue, MessageBox, YesNo};
+    ///
+    /// assert_eq!(MessageBox::<YesNo>::new("Save?").expected_buttons(), ["Yes", "No"]);
+    /// assert_eq!(
+    ///     MessageBox::<CancelTryAgainContinue>::new("Failed").expected_buttons(),
+    ///     ["Cancel", "Try Again", "Continue"]
+    /// );
+    /// ```
+    pub fn expected_buttons(&self) -> &'static [&'static str] {
+        T::captions()
+    }
+
+    /// A one-line preview of the text, e.g. for the tooltip of a tray icon before the message box is shown.
+    ///
+    /// This is the first line of the text, truncated to at most `max_chars` characters (including the ellipsis).
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let msgbox = MessageBox::<Okay>::new("Backup completed");
+    /// assert_eq!(msgbox.summary(20), "Backup completed");
+    /// assert_eq!(msgbox.summary(10), "Backup co…");
+    ///
+    /// let msgbox = MessageBox::<Okay>::new("Update available\r\nVersion 2.0 adds dark mode.");
+    /// assert_eq!(msgbox.summary(20), "Update available");
+    /// assert_eq!(MessageBox::<Okay>::new("Line\nbreak").summary(20), "Line");
+    /// ```
+    pub fn summary(&self, max_chars: usize) -> String {
+        let text = self.text.to_string_lossy();
+        let line = text.lines().next().unwrap_or_default();
+        if line.chars().count() <= max_chars {
+            return line.to_owned();
+        }
+        let mut summary: String = line.chars().take(max_chars.saturating_sub(1)).collect();
+        if max_chars > 0 {
+            summary.push('…');
+        }
+        summary
+    }
+
+    /// Renders a PowerShell command that shows an equivalent message box through
+    /// [`System.Windows.Forms.MessageBox`](https://learn.microsoft.com/dotnet/api/system.windows.forms.messagebox.show),
+    /// e.g. for documentation or remote administration.
+    ///
+    /// Only the text, title, buttons, and icon are rendered. Like `MessageBoxW`, a missing title is rendered as `Error`.
+    /// [CancelTryAgainContinue] requires PowerShell 7 (.NET Core 3.0 or later).
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay, YesNoCancel};
+    ///
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::new("Hello").title("Greeting").to_powershell(),
+    ///     "Add-Type -AssemblyName System.Windows.Forms; \
+    ///      [System.Windows.Forms.MessageBox]::Show('Hello', 'Greeting', 'OK', 'Information')"
+    /// );
+    /// assert_eq!(
+    ///     MessageBox::<YesNoCancel>::warning("Don't you want to save?").without_icon().to_powershell(),
+    ///     "Add-Type -AssemblyName System.Windows.Forms; \
+    ///      [System.Windows.Forms.MessageBox]::Show('Don''t you want to save?', 'Error', 'YesNoCancel', 'None')"
+    /// );
+    /// // Typographic quotes end the string in PowerShell as well.
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::new("Don’t ‘quote’ me").title("It’s").to_powershell(),
+    ///     "Add-Type -AssemblyName System.Windows.Forms; \
+    ///      [System.Windows.Forms.MessageBox]::Show('Don’’t ‘‘quote’’ me', 'It’’s', 'OK', 'Information')"
+    /// );
+    /// ```
+    pub fn to_powershell(&self) -> String {
+        // PowerShell also ends single-quoted strings at typographic quotes (U+2018 to U+201B),
+        // so they're doubled like `'` to keep them literal.
+        let quote = |s: &str| {
+            let mut quoted = String::with_capacity(s.len() + 2);
+            quoted.push('\'');
+            for c in s.chars() {
+                if matches!(c, '\'' | '\u{2018}'..='\u{201B}') {
+                    quoted.push(c);
+                }
+                quoted.push(c);
+            }
+            quoted.push('\'');
+            quoted
+        };
+        let buttons = match T::FLAGS {
+            MB_OKCANCEL => "OKCancel",
+            MB_ABORTRETRYIGNORE => "AbortRetryIgnore",
+            MB_YESNOCANCEL => "YesNoCancel",
+            MB_YESNO => "YesNo",
+            MB_RETRYCANCEL => "RetryCancel",
+            MB_CANCELTRYCONTINUE => "CancelTryContinue",
+            _ => "OK",
+        };
+        format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             [System.Windows.Forms.MessageBox]::Show({}, {}, '{buttons}', '{}')",
+            quote(&self.text.to_string_lossy()),
+            quote(
+                &self
+                    .title
+                    .as_ref()
+                    .map_or(Cow::Borrowed("Error"), Text::to_string_lossy)
+            ),
+            self.icon.map_or("None", Icon::name),
+        )
+    }
+
+    /// The style passed to `MessageBoxW` when this message box is [shown](Self::show).
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{
+    ///     MB_ICONINFORMATION, MB_OK, MB_RIGHT, MB_TOPMOST,
+    /// };
+    ///
+    /// let msgbox = MessageBox::<Okay>::new("Hello").topmost().right();
+    /// assert_eq!(msgbox.style(), MB_OK | MB_ICONINFORMATION | MB_TOPMOST | MB_RIGHT);
+    /// ```
+    pub fn style(&self) -> MESSAGEBOX_STYLE {
+        // The system icon reserves the space for the custom icon.
+        let icon = self.icon.or(self.custom_icon.map(|_| Icon::Information));
+        T::flags() | icon.map_or(0, Icon::style) | self.flags | self.direction_flags()
+    }
+
     /// Shows the message box, returning the option the user clicked on.
     ///
     /// If a message box has a **Cancel** button, the function returns the `Cancel` value
@@ -336,32 +2139,587 @@ impl<T: Options> MessageBox<'_, T> {
     /// unless an **Ok** button is present.
     ///
     /// If an **Ok** button is displayed and the user presses ESC, the return value will be `Ok`.
+    ///
+    /// Returns `ERROR_INVALID_PARAMETER` if an [owner](Self::hwnd) is combined with
+    /// [`service_notification`](Self::service_notification) or [`task_modal_current_thread`](Self::task_modal_current_thread).
+    ///
+    /// Returns [`MsgBoxError::TextTooLong`] or [`MsgBoxError::TitleTooLong`] if the text or title
+    /// are longer than [MAX_TEXT_UTF16_LEN] UTF-16 code units.
+    ///
+    /// Returns [`MsgBoxError::DefaultButtonOutOfRange`] if the [default button](Self::default_button)
+    /// doesn't exist (e.g. `DefaultButton3` on [YesNo]).
+    ///
+    /// Returns [`MsgBoxError::CrossThreadOwner`] if the owner belongs to another thread
+    /// and [`strict_threading`](Self::strict_threading) is set.
+    ///
+    /// Use [validate](Self::validate) to check for these errors (except for the owner's thread) without showing the message box.
+    ///
+    /// ```
+    /// # #[cfg(windows)]
+    /// # {
+    /// use win_msgbox::{MsgBoxError, Okay, MAX_TEXT_UTF16_LEN};
+    ///
+    /// let text = "a".repeat(MAX_TEXT_UTF16_LEN + 1);
+    /// assert_eq!(win_msgbox::show::<Okay>(text), Err(MsgBoxError::TextTooLong));
+    /// # }
+    /// ```
+    ///
+    /// With the `tracing` feature enabled, the message box is shown inside a `msgbox` span,
+    /// which records the return code (or error) and the time the message box was open.
     pub fn show(self) -> Result<T> {
-        let text: Vec<_> = self.text.encode_utf16().chain(std::iter::once(0)).collect();
-        let title = match self.title {
-            Some(t) => t.encode_utf16().chain(std::iter::once(0)).collect(),
-            None => Vec::new(),
+        self.show_ref()
+    }
+
+    /// Shows the message box without consuming it, otherwise the same as [show](Self::show).
+    ///
+    /// This allows showing the same message box multiple times without cloning it.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// # fn main() -> win_msgbox::Result<()> {
+    /// let msgbox = MessageBox::<YesNo>::question("Are you sure?");
+    /// if msgbox.show_ref()? == YesNo::Yes {
+    ///     msgbox.show_ref()?;
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn show_ref(&self) -> Result<T> {
+        self.show_code_unbuffered().map(T::from)
+    }
+
+    /// Shows the message box like [show_ref](Self::show_ref), encoding the text and title into `buf`.
+    ///
+    /// The buffer is cleared before it's used, so reusing it when showing many message boxes
+    /// avoids allocating for each one. Its contents afterwards are unspecified.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// # fn main() -> win_msgbox::Result<()> {
+    /// let mut buf = Vec::new();
+    /// for step in ["Download", "Install", "Restart"] {
+    ///     MessageBox::<Okay>::new(step).show_with_buffer(&mut buf)?;
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn show_with_buffer(&self, buf: &mut Vec<u16>) -> Result<T> {
+        self.show_code(buf).map(T::from)
+    }
+
+    /// Checks the configuration of the message box without showing it.
+    ///
+    /// This returns the same errors [show](Self::show) would return before showing the message box
+    /// as a [ConfigError]. It doesn't check whether the owner belongs to another thread (see [`strict_threading`](Self::strict_threading)),
+    /// since that's not part of the configuration. For the same reason, an owner set through
+    /// [`owner_by_title`](Self::owner_by_title) isn't looked up.
+    ///
+    /// ```
+    /// use win_msgbox::{ConfigError, DefaultButton, MessageBox, Okay, Owner, YesNo, MAX_TEXT_UTF16_LEN};
+    ///
+    /// let owner = Owner::from_hwnd(1 as _);
+    /// assert_eq!(MessageBox::<YesNo>::new("Hello").validate(), Ok(()));
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::new("Hello").hwnd(owner).service_notification().validate(),
+    ///     Err(ConfigError::OwnerWithServiceNotification)
+    /// );
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::new("Hello").hwnd(owner).task_modal_current_thread().validate(),
+    ///     Err(ConfigError::OwnerWithTaskModal)
+    /// );
+    /// assert_eq!(
+    ///     MessageBox::<YesNo>::new("Hello").default_button(DefaultButton::DefaultButton3).validate(),
+    ///     Err(ConfigError::DefaultButtonOutOfRange)
+    /// );
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::new("a".repeat(MAX_TEXT_UTF16_LEN + 1)).validate(),
+    ///     Err(ConfigError::TextTooLong)
+    /// );
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::new("Hello").title("a".repeat(MAX_TEXT_UTF16_LEN + 1)).validate(),
+    ///     Err(ConfigError::TitleTooLong)
+    /// );
+    /// ```
+    pub fn validate(&self) -> core::result::Result<(), ConfigError> {
+        let mut buf = Vec::new();
+        let (text, title) = self.encode(&mut buf);
+        self.check(self.hwnd, text, title)
+    }
+
+    /// Measures the size of the message box (including its frame) in pixels without showing it to the user,
+    /// e.g. to place a following window next to it.
+    ///
+    /// Windows doesn't provide a way to compute the layout of a message box, so this is a hack:
+    /// the message box is created, and once it's activated, a hook makes it transparent,
+    /// reads its size through `GetWindowRect`, and ends it right away.
+    /// This is fragile - the message box might flash briefly, it takes the focus for a moment,
+    /// and the system might still play the sound of its [icon](Self::icon) (use [silent](Self::silent) to avoid that).
+    ///
+    /// The message box is measured without its owner and hooks (e.g. [`on_created`](Self::on_created) isn't called),
+    /// but with its [language](Self::language) and [DPI awareness](Self::dpi_aware), since they change the size.
+    /// Canned responses of the `testing` module aren't consumed and [last_result] isn't changed.
+    ///
+    /// Returns `ERROR_NOT_SUPPORTED` for [service notifications](Self::service_notification) and [default desktop](Self::default_desktop_only)
+    /// message boxes, since they're not created by the current thread.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// # fn main() -> win_msgbox::Result<()> {
+    /// let msgbox = MessageBox::<YesNo>::question("Save the changes?").silent();
+    /// let (width, height) = msgbox.measure()?;
+    /// println!("The message box is {width}x{height} pixels large");
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn measure(&self) -> Result<(u32, u32)> {
+        if self.flags & (MB_SERVICE_NOTIFICATION | MB_DEFAULT_DESKTOP_ONLY) != 0 {
+            return Err(ERROR_NOT_SUPPORTED.into());
+        }
+        let mut buf = Vec::new();
+        let (text, title) = self.encode(&mut buf);
+        self.check(std::ptr::null_mut(), text, title)?;
+
+        let size = Rc::new(Cell::new(None));
+        let _hook = CbtHook::install({
+            let size = Rc::clone(&size);
+            move |hwnd| size.set(Some(hook::measure_and_end(hwnd)))
+        })?;
+        let _dpi_scope = if self.dpi_aware {
+            DpiAwarenessScope::enter()
+        } else {
+            None
         };
 
+        let title = if title.is_empty() {
+            std::ptr::null()
+        } else {
+            title.as_ptr()
+        };
+        // `message_box_w` isn't used, as it would consume canned responses and record the result.
         let return_code = unsafe {
-            MessageBoxW(
-                self.hwnd,
+            match self.language {
+                Some(language) => MessageBoxExW(
+                    std::ptr::null_mut(),
+                    text.as_ptr(),
+                    title,
+                    self.style(),
+                    language,
+                ),
+                None => MessageBoxW(std::ptr::null_mut(), text.as_ptr(), title, self.style()),
+            }
+        };
+        if return_code == 0 {
+            return Err(unsafe { GetLastError() }.into());
+        }
+        size.get().ok_or(ERROR_NOT_SUPPORTED.into())
+    }
+
+    /// Encodes the text (normalizing its lines if requested) and title (empty if there's none)
+    /// as null-terminated UTF-16 into `buf`, which is cleared first.
+    fn encode<'b>(&self, buf: &'b mut Vec<u16>) -> (&'b [u16], &'b [u16]) {
+        buf.clear();
+        self.text.encode_into(buf, self.normalize_newlines);
+        let text_len = buf.len();
+        if let Some(title) = &self.title {
+            title.encode_into(buf, false);
+        }
+        buf.split_at(text_len)
+    }
+
+    /// Checks the configuration with the owner `hwnd` and the [encoded](Self::encode) `text` and `title`.
+    fn check(
+        &self,
+        hwnd: HWND,
+        text: &[u16],
+        title: &[u16],
+    ) -> core::result::Result<(), ConfigError> {
+        if !hwnd.is_null() {
+            if self.flags & MB_SERVICE_NOTIFICATION != 0 {
+                return Err(ConfigError::OwnerWithServiceNotification);
+            }
+            if self.thread_modal {
+                return Err(ConfigError::OwnerWithTaskModal);
+            }
+        }
+        check_default_button::<T>(self.flags)?;
+        // Both strings are null terminated.
+        if text.len() > MAX_TEXT_UTF16_LEN + 1 {
+            return Err(ConfigError::TextTooLong);
+        }
+        if title.len() > MAX_TEXT_UTF16_LEN + 1 {
+            return Err(ConfigError::TitleTooLong);
+        }
+        Ok(())
+    }
+
+    /// Encodes a short ASCII text without a title into `buf`, returning the null-terminated text.
+    ///
+    /// Returns `None` if the message box doesn't qualify, so it has to be [encoded](Self::encode) into a `Vec`.
+    fn encode_short<'b>(&self, buf: &'b mut [u16; SHORT_TEXT_LEN]) -> Option<&'b [u16]> {
+        let Text::Str(text) = &self.text else {
+            return None;
+        };
+        let needs_normalizing = self.normalize_newlines && text.contains(['\r', '\n']);
+        if self.title.is_some() || text.len() >= buf.len() || !text.is_ascii() || needs_normalizing
+        {
+            return None;
+        }
+        for (unit, byte) in buf.iter_mut().zip(text.bytes()) {
+            *unit = byte.into();
+        }
+        buf[text.len()] = 0;
+        Some(&buf[..=text.len()])
+    }
+
+    /// Shows the message box like [show_code](Self::show_code),
+    /// encoding short texts without a title on the stack instead of allocating a buffer.
+    fn show_code_unbuffered(&self) -> Result<MESSAGEBOX_RESULT> {
+        let mut short = [0; SHORT_TEXT_LEN];
+        match self.encode_short(&mut short) {
+            Some(text) => self.show_encoded(text, &[]),
+            None => self.show_code(&mut Vec::new()),
+        }
+    }
+
+    /// Shows the message box, encoding it into `buf`, and returns the raw code returned by `MessageBoxW`.
+    fn show_code(&self, buf: &mut Vec<u16>) -> Result<MESSAGEBOX_RESULT> {
+        let (text, title) = self.encode(buf);
+        self.show_encoded(text, title)
+    }
+
+    /// Shows the message box with the encoded `text` and `title` (empty if there's none),
+    /// returning the raw code returned by `MessageBoxW`.
+    fn show_encoded(&self, text: &[u16], title: &[u16]) -> Result<MESSAGEBOX_RESULT> {
+        let hwnd = self.owner()?;
+        self.check(hwnd, text, title)?;
+        check_owner_thread(hwnd, self.strict_threading)?;
+
+        #[cfg(feature = "tracing")]
+        let span = trace::DialogSpan::enter::<T>(
+            self.title.as_ref().map(Text::to_string_lossy).as_deref(),
+        );
+
+        let _hook = CbtHook::for_dialog(DialogHooks {
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            custom_icon: self.custom_icon,
+            on_created: self.on_created.clone(),
+        })?;
+        let _escape_hook = if self.escapable {
+            Some(EscapeHook::install()?)
+        } else {
+            None
+        };
+        // Without support for per-monitor DPI awareness, the message box is shown as before.
+        let _dpi_scope = if self.dpi_aware {
+            DpiAwarenessScope::enter()
+        } else {
+            None
+        };
+
+        if let Some(sound) = self.sound {
+            // The sound is only cosmetic, so failing to play it isn't treated as an error.
+            unsafe { MessageBeep(sound as u32) };
+        }
+        if let Some(path) = self.sound_file {
+            let path: Vec<u16> = path.encode_utf16().chain(std::iter::once(0)).collect();
+            // Like the beep, the sound is only cosmetic. `SND_NODEFAULT` prevents the default sound
+            // from being played if the file can't be found.
+            unsafe {
+                PlaySoundW(
+                    path.as_ptr(),
+                    std::ptr::null_mut(),
+                    SND_FILENAME | SND_ASYNC | SND_NODEFAULT,
+                )
+            };
+        }
+
+        let previous_foreground = if self.restore_focus && hwnd.is_null() {
+            unsafe { GetForegroundWindow() }
+        } else {
+            std::ptr::null_mut()
+        };
+
+        let return_code = unsafe {
+            message_box_w(
+                hwnd,
                 text.as_ptr(),
                 if title.is_empty() {
                     std::ptr::null()
                 } else {
                     title.as_ptr()
                 },
-                T::flags() | self.icon.style() | self.flags,
+                self.style(),
+                self.language,
             )
         };
-        match return_code {
-            0 => Err(unsafe { GetLastError() }),
-            x => Ok(T::from(x)),
+        let response = match return_code {
+            0 => Err(unsafe { GetLastError() }.into()),
+            x => Ok(x),
+        };
+        if !previous_foreground.is_null() && unsafe { IsWindow(previous_foreground) } != 0 {
+            unsafe { SetForegroundWindow(previous_foreground) };
+        }
+
+        #[cfg(feature = "tracing")]
+        span.finish(response);
+
+        response
+    }
+
+    /// Shows the message box and closes it after `duration`, returning `on_timeout` in that case.
+    ///
+    /// Unlike [`DialogCloser`], this works for all options, because the message box is ended directly
+    /// through a timer on the current thread (set up like [`on_created`](Self::on_created)).
+    /// If the user selects a button right before the deadline, either the selection or `on_timeout` may be returned.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// # fn main() -> win_msgbox::Result<()> {
+    /// let response = MessageBox::<YesNo>::question("Install the update now?")
+    ///     .show_for(Duration::from_secs(30), YesNo::No)?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn show_for(self, duration: Duration, on_timeout: T) -> Result<T> {
+        let millis = duration.as_millis().min(USER_TIMER_MAXIMUM.into()) as u32;
+        let msgbox = self.also_on_created(move |hwnd| end_dialog_after(hwnd, millis));
+        match msgbox.show_code_unbuffered()? {
+            IDTIMEOUT => Ok(on_timeout),
+            code => Ok(T::from(code)),
+        }
+    }
+}
+
+#[cfg(feature = "capture")]
+impl<T: Options> MessageBox<'_, T> {
+    /// Shows the message box and saves a screenshot of it to `path` as a bitmap (`.bmp`).
+    ///
+    /// The screenshot is taken through [`on_created`](Self::on_created) once the message box exists,
+    /// so it shows the message box before the user interacts with it. A callback set through `on_created` still runs.
+    ///
+    /// Returns an error if the screenshot couldn't be taken or saved, even if the message box was shown.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// MessageBox::<Okay>::new("Hello").show_and_capture(Path::new("hello.bmp")).unwrap();
+    /// ```
+    pub fn show_and_capture(self, path: &std::path::Path) -> Result<T> {
+        use std::sync::PoisonError;
+
+        let path = path.to_path_buf();
+        let captured = Arc::new(Mutex::new(Ok(())));
+        let msgbox = self.also_on_created({
+            let captured = captured.clone();
+            move |hwnd| {
+                if let Ok(mut captured) = captured.lock() {
+                    *captured = capture::capture_window(hwnd, &path);
+                }
+            }
+        });
+        let response = msgbox.show()?;
+        let captured = *captured.lock().unwrap_or_else(PoisonError::into_inner);
+        captured.map(|()| response)
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<T: Options> MessageBox<'_, T> {
+    /// Shows the message box repeatedly while `pred` returns `true` for the response,
+    /// returning the first response for which it returned `false`.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, RetryCancel};
+    ///
+    /// # fn main() -> win_msgbox::Result<()> {
+    /// let response = MessageBox::<RetryCancel>::error("Still not working")
+    ///     .show_while(|r| *r == RetryCancel::Retry)?;
+    /// assert_eq!(response, RetryCancel::Cancel);
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn show_while<F>(self, mut pred: F) -> Result<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        loop {
+            let response = self.show_ref()?;
+            if !pred(&response) {
+                return Ok(response);
+            }
         }
     }
+
+    /// Shows the message box with a [Help button](Self::with_help), returning the response
+    /// and how often the user asked for help (by clicking the button or pressing F1).
+    ///
+    /// This shows the message box through
+    /// [MessageBoxIndirectW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxindirectw)
+    /// with a callback counting the presses,
+    /// so the owner doesn't receive [WM_HELP](https://learn.microsoft.com/windows/desktop/shell/wm-help) messages.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// let (response, presses) = MessageBox::<YesNo>::question("Apply the update?").show_collecting_help();
+    /// println!("Help was requested {presses} times before choosing {:?}", response?);
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    pub fn show_collecting_help(self) -> (Result<T>, u32) {
+        let mut presses = 0u32;
+        let response = self.show_with_help_handler(|_| presses = presses.saturating_add(1));
+        (response, presses)
+    }
+
+    /// Shows the message box with a [Help button](Self::with_help), calling `handler`
+    /// whenever the user asks for help (by clicking the button or pressing F1).
+    ///
+    /// Like [show_collecting_help](Self::show_collecting_help), this shows the message box through
+    /// [MessageBoxIndirectW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxindirectw),
+    /// so the owner doesn't receive [WM_HELP](https://learn.microsoft.com/windows/desktop/shell/wm-help) messages.
+    /// The message box stays open while the handler runs.
+    ///
+    /// A panic in the handler can't unwind through the message box, so it's caught and the message box stays open.
+    /// The handler is still called for later requests.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// let response = MessageBox::<YesNo>::question("Apply the update?").show_with_help_handler(|info| {
+    ///     let (x, y) = info.mouse_pos;
+    ///     println!("Help requested at {x}, {y}");
+    /// })?;
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    pub fn show_with_help_handler(self, mut handler: impl FnMut(HelpInfo)) -> Result<T> {
+        help::with_handler(&mut handler, || self.with_help().show_ref())
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<T: Confirmable> MessageBox<'_, T> {
+    /// Shows the message box, returning `Ok(())` if the user selected the affirmative button
+    /// and [`MsgBoxError::Declined`] otherwise - for aborting with `?`.
+    ///
+    /// The affirmative buttons are:
+    ///
+    /// | Option        | Confirmed |
+    /// |---------------|-----------|
+    /// | [OkayCancel]  | **OK**    |
+    /// | [YesNo]       | **Yes**   |
+    /// | [YesNoCancel] | **Yes**   |
+    /// | [RetryCancel] | **Retry** |
+    ///
+    /// ```
+    /// # #[cfg(all(windows, feature = "testing"))]
+    /// # {
+    /// use win_msgbox::{testing, MessageBox, MsgBoxError, YesNoCancel};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDNO, IDYES};
+    ///
+    /// fn overwrite() -> win_msgbox::Result<&'static str> {
+    ///     MessageBox::<YesNoCancel>::question("Overwrite the file?").show_confirmed()?;
+    ///     Ok("overwritten")
+    /// }
+    ///
+    /// testing::push_response(IDYES);
+    /// assert_eq!(overwrite(), Ok("overwritten"));
+    /// testing::push_response(IDNO);
+    /// assert_eq!(overwrite(), Err(MsgBoxError::Declined));
+    /// testing::push_response(IDCANCEL);
+    /// assert_eq!(overwrite(), Err(MsgBoxError::Declined));
+    /// # }
+    /// ```
+    pub fn show_confirmed(self) -> Result<()> {
+        if self.show()?.is_confirmed() {
+            Ok(())
+        } else {
+            Err(MsgBoxError::Declined)
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl<T: Options + std::fmt::Display> MessageBox<'_, T> {
+    /// Shows the message box like [show](Self::show) and logs it as well as the response
+    /// using the [`log`](https://docs.rs/log) crate.
+    ///
+    /// Long titles and texts are truncated in the log.
+    pub fn show_and_log(self) -> Result<T> {
+        let title = self.title.as_ref().map(Text::to_string_lossy);
+        let text = self.text.to_string_lossy();
+        log::info!(
+            "Showing message box: title={:?} text={:?}",
+            title.as_deref().map(truncate_for_log),
+            truncate_for_log(&text)
+        );
+        let response = self.show();
+        match &response {
+            Ok(option) => log::info!("Message box returned: {option}"),
+            Err(e) => log::error!("Failed to show message box: error={e}"),
+        }
+        response
+    }
+}
+
+/// Maps `Error` to [Error](Icon::Error), `Warn` to [Warning](Icon::Warning),
+/// and `Info`, `Debug`, and `Trace` to [Information](Icon::Information).
+#[cfg(feature = "log")]
+impl From<log::Level> for Icon {
+    fn from(value: log::Level) -> Self {
+        match value {
+            log::Level::Error => Icon::Error,
+            log::Level::Warn => Icon::Warning,
+            log::Level::Info | log::Level::Debug | log::Level::Trace => Icon::Information,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl MessageBox<'static, Okay> {
+    /// Creates a new message box showing the message of `record`
+    /// with the [Icon] matching its level.
+    ///
+    /// ```
+    /// # #[cfg(feature = "log")]
+    /// # {
+    /// use log::{Level, Record};
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let msgbox = MessageBox::from_record(
+    ///     &Record::builder()
+    ///         .args(format_args!("Disk {} is full", "C:"))
+    ///         .level(Level::Warn)
+    ///         .build(),
+    /// );
+    /// assert!(format!("{msgbox:?}").contains(r#"text: "Disk C: is full", icon: Some(Warning)"#));
+    /// # }
+    /// ```
+    pub fn from_record(record: &log::Record) -> Self {
+        Self::new(record.args().to_string()).icon(record.level().into())
+    }
 }
 
+/// Truncates `s` to at most 128 characters.
+#[cfg(feature = "log")]
+fn truncate_for_log(s: &str) -> &str {
+    match s.char_indices().nth(128) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
+#[cfg(feature = "safe")]
 ctors! {
     exclamation => Exclamation,
     warning => Warning,
@@ -373,9 +2731,203 @@ ctors! {
     hand => Hand
 }
 
+/// Replaces lone linefeeds (`\n`) and carriage returns (`\r`) in `text` with `\r\n`,
+/// keeping existing `\r\n` sequences.
+///
+/// This is the conversion done by [`MessageBox::normalize_newlines`].
+///
+/// ```
+/// assert_eq!(
+///     win_msgbox::normalize_newlines("unix\nwindows\r\nmac\rend"),
+///     "unix\r\nwindows\r\nmac\r\nend"
+/// );
+/// assert_eq!(win_msgbox::normalize_newlines("\r\r\n\n\r"), "\r\n\r\n\r\n\r\n");
+/// assert_eq!(win_msgbox::normalize_newlines("no newlines"), "no newlines");
+/// ```
+pub fn normalize_newlines(text: &str) -> String {
+    text::normalize_newlines(text.chars(), '\r', '\n')
+        .into_iter()
+        .collect()
+}
+
+/// Creates a new message box with the [Info](Severity::Info) severity.
+///
+/// For more options see [MessageBox].
+#[cfg(feature = "safe")]
+pub fn info<'a, T: Options>(text: impl Into<Cow<'a, str>>) -> MessageBox<'a, T> {
+    MessageBox::new(text).severity(Severity::Info)
+}
+
+/// Creates a new message box with the [Warning](Severity::Warning) severity.
+///
+/// Unlike [warning], this also selects the [safe button](Options::safe_button) as the default button.
+#[cfg(feature = "safe")]
+pub fn warn<'a, T: Options>(text: impl Into<Cow<'a, str>>) -> MessageBox<'a, T> {
+    MessageBox::new(text).severity(Severity::Warning)
+}
+
 /// Shows a message box with a specified `text` to be displayed.
 ///
 /// For more options see [MessageBox].
-pub fn show<T: Options>(text: &str) -> Result<T> {
+#[cfg(feature = "safe")]
+pub fn show<'a, T: Options>(text: impl Into<Cow<'a, str>>) -> Result<T> {
     MessageBox::new(text).show()
 }
+
+/// Shows the message boxes one after another (e.g. the steps of a wizard), collecting the responses.
+///
+/// Stops at the first error, returning it.
+///
+/// ```
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::{testing, MessageBox, YesNo};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDNO, IDYES};
+///
+/// testing::push_response(IDYES);
+/// testing::push_response(IDNO);
+/// let responses = win_msgbox::show_sequence([
+///     MessageBox::<YesNo>::question("Install the desktop shortcut?"),
+///     MessageBox::<YesNo>::question("Start on login?"),
+/// ]);
+/// assert_eq!(responses, Ok(vec![YesNo::Yes, YesNo::No]));
+/// # }
+/// ```
+#[cfg(feature = "safe")]
+pub fn show_sequence<'a, T: Options>(
+    boxes: impl IntoIterator<Item = MessageBox<'a, T>>,
+) -> Result<Vec<T>> {
+    show_sequence_while(boxes, |_| true)
+}
+
+/// Shows the message boxes one after another like [show_sequence],
+/// but stops after the first response for which `pred` returns `false`.
+///
+/// ```
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::{testing, MessageBox, OkayCancel};
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDOK};
+///
+/// testing::push_response(IDOK);
+/// testing::push_response(IDCANCEL);
+/// testing::push_response(IDOK);
+/// let steps = ["Welcome", "License", "Install"].map(MessageBox::<OkayCancel>::new);
+/// let responses = win_msgbox::show_sequence_while(steps, |r| *r == OkayCancel::Okay);
+/// assert_eq!(responses, Ok(vec![OkayCancel::Okay, OkayCancel::Cancel]));
+/// testing::clear_responses();
+/// # }
+/// ```
+#[cfg(feature = "safe")]
+pub fn show_sequence_while<'a, T: Options>(
+    boxes: impl IntoIterator<Item = MessageBox<'a, T>>,
+    mut pred: impl FnMut(&T) -> bool,
+) -> Result<Vec<T>> {
+    let mut responses = Vec::new();
+    for msgbox in boxes {
+        let response = msgbox.show()?;
+        let proceed = pred(&response);
+        responses.push(response);
+        if !proceed {
+            break;
+        }
+    }
+    Ok(responses)
+}
+
+/// Asks the user to confirm something, returning `true` if they selected **Yes**.
+///
+/// This shows a [YesNo] message box with the [Warning](Icon::Warning) icon.
+/// **Yes** is the default button. For more options see [MessageBox].
+///
+/// ```
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::testing;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDNO, IDYES};
+///
+/// testing::push_response(IDYES);
+/// assert_eq!(win_msgbox::confirm("Overwrite the file?"), Ok(true));
+/// testing::push_response(IDNO);
+/// assert_eq!(win_msgbox::confirm("Overwrite the file?"), Ok(false));
+/// # }
+/// ```
+#[cfg(feature = "safe")]
+pub fn confirm(text: &str) -> Result<bool> {
+    warning::<YesNo>(text).show().map(bool::from)
+}
+
+/// Asks the user to confirm something like [confirm], but with a `title`.
+///
+/// ```
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::testing;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDNO, IDYES};
+///
+/// testing::push_response(IDYES);
+/// assert_eq!(win_msgbox::confirm_with_title("Quit without saving?", "Editor"), Ok(true));
+/// testing::push_response(IDNO);
+/// assert_eq!(win_msgbox::confirm_with_title("Quit without saving?", "Editor"), Ok(false));
+/// # }
+/// ```
+#[cfg(feature = "safe")]
+pub fn confirm_with_title(text: &str, title: &str) -> Result<bool> {
+    warning::<YesNo>(text).title(title).show().map(bool::from)
+}
+
+/// Shows an error to the user and waits until they acknowledge it.
+///
+/// This shows an [Okay] message box with the [Error](Icon::Error) icon,
+/// since the user can't do anything but acknowledge the error. For more options see [MessageBox].
+///
+/// ```no_run
+/// win_msgbox::alert("The configuration file is corrupted.")?;
+/// # Ok::<(), win_msgbox::MsgBoxError>(())
+/// ```
+#[cfg(feature = "safe")]
+pub fn alert(text: &str) -> Result<()> {
+    error::<Okay>(text).show().map(drop)
+}
+
+/// Asks the user whether to retry a failed operation, returning `true` if they selected **Retry**.
+///
+/// This shows a [RetryCancel] message box with the [Error](Icon::Error) icon.
+/// **Retry** is the default button. For more options see [MessageBox].
+///
+/// ```
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// use win_msgbox::testing;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDRETRY};
+///
+/// testing::push_response(IDRETRY);
+/// assert_eq!(win_msgbox::prompt_retry("Couldn't save the file."), Ok(true));
+/// testing::push_response(IDCANCEL);
+/// assert_eq!(win_msgbox::prompt_retry("Couldn't save the file."), Ok(false));
+/// # }
+/// ```
+#[cfg(feature = "safe")]
+pub fn prompt_retry(text: &str) -> Result<bool> {
+    error::<RetryCancel>(text).show().map(bool::from)
+}
+
+/// Creates a new message box where the text consists of `lines`,
+/// separated by a carriage return and linefeed (`\r\n`).
+///
+/// For more options see [MessageBox].
+#[cfg(feature = "safe")]
+pub fn from_lines<'a, T>(lines: &[&str]) -> MessageBox<'a, T> {
+    MessageBox::lines(lines)
+}
+
+/// Creates a new message box with a specified `text` and `title`.
+///
+/// For more options see [MessageBox].
+#[cfg(feature = "safe")]
+pub fn captioned<'a, T>(
+    text: impl Into<Cow<'a, str>>,
+    title: impl Into<Cow<'a, str>>,
+) -> MessageBox<'a, T> {
+    MessageBox::captioned(text, title)
+}
@@ -0,0 +1,116 @@
+use windows_sys::Win32::{
+    Foundation::{BOOL, FALSE, HWND, LPARAM, POINT, RECT, TRUE},
+    Graphics::Gdi::{
+        EnumDisplayMonitors, GetMonitorInfoW, MonitorFromPoint, HDC, HMONITOR, MONITORINFO,
+        MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTOPRIMARY,
+    },
+    UI::WindowsAndMessaging::{GetCursorPos, GetWindowRect},
+};
+
+/// Selects the monitor a message box is shown on through [`monitor`](crate::MessageBox::monitor).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MonitorSelector {
+    /// The primary monitor.
+    Primary,
+    /// The monitor the mouse cursor is on.
+    WithCursor,
+    /// The nth monitor (starting at `0`) in the order of
+    /// [EnumDisplayMonitors](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-enumdisplaymonitors).
+    Index(u32),
+}
+
+/// Centers `hwnd` in the work area of the selected monitor.
+///
+/// If the monitor doesn't exist, the window isn't moved.
+pub(crate) fn center_on_monitor(hwnd: HWND, selector: MonitorSelector) {
+    let Some(work_area) = find_monitor(selector).and_then(work_area) else {
+        return;
+    };
+    let mut window: RECT = unsafe { std::mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut window) } == 0 {
+        return;
+    }
+    let width = window.right - window.left;
+    let height = window.bottom - window.top;
+    crate::hook::move_window(
+        hwnd,
+        work_area.left + (work_area.right - work_area.left - width) / 2,
+        work_area.top + (work_area.bottom - work_area.top - height) / 2,
+    );
+}
+
+fn find_monitor(selector: MonitorSelector) -> Option<HMONITOR> {
+    let origin = POINT { x: 0, y: 0 };
+    match selector {
+        // The primary monitor has its top left corner at (0, 0).
+        MonitorSelector::Primary => {
+            Some(unsafe { MonitorFromPoint(origin, MONITOR_DEFAULTTOPRIMARY) })
+        }
+        MonitorSelector::WithCursor => {
+            let mut cursor = origin;
+            if unsafe { GetCursorPos(&mut cursor) } == 0 {
+                return None;
+            }
+            Some(unsafe { MonitorFromPoint(cursor, MONITOR_DEFAULTTONEAREST) })
+        }
+        MonitorSelector::Index(index) => {
+            let mut search = (index, None);
+            unsafe {
+                EnumDisplayMonitors(
+                    std::ptr::null_mut(),
+                    std::ptr::null(),
+                    Some(nth_monitor),
+                    &mut search as *mut (u32, Option<HMONITOR>) as LPARAM,
+                )
+            };
+            search.1
+        }
+    }
+}
+
+/// The number of monitors, as enumerated by `EnumDisplayMonitors`.
+#[cfg(feature = "safe")]
+pub(crate) fn monitor_count() -> u32 {
+    let mut count = 0u32;
+    unsafe {
+        EnumDisplayMonitors(
+            std::ptr::null_mut(),
+            std::ptr::null(),
+            Some(count_monitor),
+            &mut count as *mut u32 as LPARAM,
+        )
+    };
+    count
+}
+
+/// Increments the `u32` `lparam` points to.
+#[cfg(feature = "safe")]
+unsafe extern "system" fn count_monitor(_: HMONITOR, _: HDC, _: *mut RECT, lparam: LPARAM) -> BOOL {
+    *(lparam as *mut u32) += 1;
+    TRUE
+}
+
+/// Counts down the index in `lparam` and stores the monitor once it reaches `0`.
+///
+/// `lparam` points to a `(u32, Option<HMONITOR>)`.
+unsafe extern "system" fn nth_monitor(
+    monitor: HMONITOR,
+    _: HDC,
+    _: *mut RECT,
+    lparam: LPARAM,
+) -> BOOL {
+    let search = &mut *(lparam as *mut (u32, Option<HMONITOR>));
+    if search.0 > 0 {
+        search.0 -= 1;
+        return TRUE;
+    }
+    search.1 = Some(monitor);
+    FALSE
+}
+
+fn work_area(monitor: HMONITOR) -> Option<RECT> {
+    let mut info: MONITORINFO = unsafe { std::mem::zeroed() };
+    info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+    (unsafe { GetMonitorInfoW(monitor, &mut info) } != 0).then_some(info.rcWork)
+}
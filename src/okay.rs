@@ -1,18 +1,57 @@
-use super::Options;
-use windows_sys::Win32::UI::WindowsAndMessaging::{MB_OK, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE};
+use super::{DefaultButton, Options, UnknownResult};
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    IDOK, MB_OK, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+};
 
 /// The message box contains one push button: `OK`.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Okay;
 
+/// Any code is mapped to [Okay]. Use [`try_from_code`](Self::try_from_code) to reject unknown codes.
 impl From<MESSAGEBOX_RESULT> for Okay {
     fn from(_: MESSAGEBOX_RESULT) -> Self {
         Self
     }
 }
 
+impl Okay {
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDOK => Ok(Self),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::Okay;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::IDOK;
+///
+/// assert!(Okay == IDOK);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for Okay {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        *other == IDOK
+    }
+}
+
 impl Options for Okay {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_OK
+    const FLAGS: MESSAGEBOX_STYLE = MB_OK;
+    const CAPTIONS: &'static [&'static str] = &["OK"];
+
+    fn safe_button() -> DefaultButton {
+        DefaultButton::DefaultButton1
+    }
+}
+
+impl std::fmt::Display for Okay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OK")
     }
 }
@@ -1,10 +1,11 @@
-use super::Options;
+use super::{Confirmable, DefaultButton, Options, UnknownResult};
 use windows_sys::Win32::UI::WindowsAndMessaging::{
-    IDOK, MB_OKCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDCANCEL, IDOK, MB_OKCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// The message box contains two push buttons: **OK** and **Cancel**.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OkayCancel {
     /// The **OK** button was selected.
     Okay,
@@ -12,6 +13,7 @@ pub enum OkayCancel {
     Cancel,
 }
 
+/// Unknown codes are mapped to [`Cancel`](OkayCancel::Cancel). Use [`try_from_code`](Self::try_from_code) to reject them.
 impl From<MESSAGEBOX_RESULT> for OkayCancel {
     fn from(value: MESSAGEBOX_RESULT) -> Self {
         match value {
@@ -21,8 +23,87 @@ impl From<MESSAGEBOX_RESULT> for OkayCancel {
     }
 }
 
+impl OkayCancel {
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDOK => Ok(Self::Okay),
+            IDCANCEL => Ok(Self::Cancel),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// [`Okay`](OkayCancel::Okay) is `true` and [`Cancel`](OkayCancel::Cancel) is `false`.
+///
+/// ```
+/// use win_msgbox::OkayCancel;
+///
+/// assert!(bool::from(OkayCancel::Okay));
+/// assert!(!bool::from(OkayCancel::Cancel));
+/// assert_eq!(OkayCancel::from(true), OkayCancel::Okay);
+/// assert_eq!(OkayCancel::from(false), OkayCancel::Cancel);
+/// ```
+impl From<OkayCancel> for bool {
+    fn from(value: OkayCancel) -> Self {
+        value == OkayCancel::Okay
+    }
+}
+
+/// `true` is [`Okay`](OkayCancel::Okay) and `false` is [`Cancel`](OkayCancel::Cancel).
+impl From<bool> for OkayCancel {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Okay
+        } else {
+            Self::Cancel
+        }
+    }
+}
+
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::OkayCancel;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDOK};
+///
+/// assert!(OkayCancel::Cancel == IDCANCEL);
+/// assert!(OkayCancel::Cancel != IDOK);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for OkayCancel {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        let code = match self {
+            Self::Okay => IDOK,
+            Self::Cancel => IDCANCEL,
+        };
+        *other == code
+    }
+}
+
 impl Options for OkayCancel {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_OKCANCEL
+    const FLAGS: MESSAGEBOX_STYLE = MB_OKCANCEL;
+    const CAPTIONS: &'static [&'static str] = &["OK", "Cancel"];
+
+    fn safe_button() -> DefaultButton {
+        // Cancel
+        DefaultButton::DefaultButton2
+    }
+}
+
+/// [`Okay`](OkayCancel::Okay) is confirmed.
+impl Confirmable for OkayCancel {
+    fn is_confirmed(&self) -> bool {
+        *self == Self::Okay
+    }
+}
+
+impl std::fmt::Display for OkayCancel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Okay => "OK",
+            Self::Cancel => "Cancel",
+        })
     }
 }
@@ -0,0 +1,103 @@
+use crate::{Icon, MessageBox, Options, Result};
+use std::marker::PhantomData;
+use windows_sys::Win32::UI::WindowsAndMessaging::MESSAGEBOX_STYLE;
+
+/// An owned message box configuration that can be sent to other threads.
+///
+/// A [MessageBox] borrows its strings and may contain an owner window, so it can't cross threads easily.
+/// This only stores the text, title, icon, and [flags](MessageBox::with_flags), and creates
+/// a [MessageBox] through [into_message_box](Self::into_message_box) on the thread that shows it.
+///
+/// ```
+/// use std::thread;
+/// use win_msgbox::{MessageBox, OwnedMessageBox, Icon, YesNo};
+///
+/// let config = OwnedMessageBox::<YesNo>::new(format!("{} files changed. Commit?", 3))
+///     .title("Sync")
+///     .icon(Icon::Question);
+///
+/// let worker = thread::spawn(move || {
+///     let msgbox = config.into_message_box();
+///     assert_eq!(msgbox, MessageBox::question("3 files changed. Commit?").title("Sync"));
+///     # #[cfg(all(windows, feature = "testing"))]
+///     # {
+///     # win_msgbox::testing::push_response(windows_sys::Win32::UI::WindowsAndMessaging::IDYES);
+///     # assert_eq!(config.show(), Ok(YesNo::Yes));
+///     # }
+/// });
+/// worker.join().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OwnedMessageBox<T> {
+    /// The text inside the message box.
+    text: String,
+    /// The title of the message box (default is None).
+    title: Option<String>,
+    /// The icon of the message box (default is [Information](Icon::Information)).
+    icon: Option<Icon>,
+    /// Additional flags, see [`MessageBox::with_flags`].
+    flags: MESSAGEBOX_STYLE,
+    /// The response options of message box.
+    _response: PhantomData<T>,
+}
+
+impl<T> OwnedMessageBox<T> {
+    /// Creates a new message box configuration with a specified `text`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            title: None,
+            icon: Some(Icon::Information),
+            flags: 0,
+            _response: PhantomData,
+        }
+    }
+
+    /// Sets the title, see [`MessageBox::title`].
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the icon, see [`MessageBox::icon`].
+    #[must_use]
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Removes the icon, see [`MessageBox::without_icon`].
+    #[must_use]
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    /// Adds raw flags, see [`MessageBox::with_flags`].
+    #[must_use]
+    pub fn with_flags(mut self, flags: MESSAGEBOX_STYLE) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Creates a [MessageBox] borrowing the text and title of this configuration,
+    /// e.g. to configure it further before showing it.
+    pub fn into_message_box(&self) -> MessageBox<'_, T> {
+        let mut msgbox = MessageBox::new(self.text.as_str()).with_flags(self.flags);
+        msgbox.icon = self.icon;
+        if let Some(title) = &self.title {
+            msgbox = msgbox.title(title.as_str());
+        }
+        msgbox
+    }
+}
+
+impl<T: Options> OwnedMessageBox<T> {
+    /// Shows the message box on the current thread, returning the option the user clicked on.
+    ///
+    /// See [`MessageBox::show`] for details.
+    pub fn show(self) -> Result<T> {
+        self.into_message_box().show()
+    }
+}
@@ -0,0 +1,28 @@
+//! Re-exports of the commonly used items.
+//!
+//! This includes [MessageBox], [show], all options, [Icon], [Modal], [DefaultButton], and [Result].
+//! The variants of the options aren't included, because their names (e.g. `Cancel`) overlap between options.
+//! Import them from the option instead (e.g. `use win_msgbox::YesNo::*`).
+//!
+//! Note that [Result] shadows the `Result` from the standard library.
+//!
+//! ```
+//! use win_msgbox::prelude::*;
+//!
+//! fn confirm_delete(name: &str) -> MessageBox<'_, YesNo> {
+//!     MessageBox::new(format!("Do you want to delete {name}?"))
+//!         .title("Delete")
+//!         .icon(Icon::Question)
+//!         .default_button(DefaultButton::DefaultButton2)
+//!         .modal(Modal::Task)
+//! }
+//!
+//! let msgbox = confirm_delete("notes.txt");
+//! assert!(format!("{msgbox:?}").contains("Do you want to delete notes.txt?"));
+//! ```
+#[cfg(feature = "safe")]
+pub use crate::{show, MessageBox};
+pub use crate::{
+    AbortRetryIgnore, CancelTryAgainContinue, DefaultButton, Icon, Modal, Okay, OkayCancel, Result,
+    RetryCancel, YesNo, YesNoCancel,
+};
@@ -0,0 +1,107 @@
+use windows_sys::Win32::{
+    Foundation::HWND,
+    UI::WindowsAndMessaging::{GetForegroundWindow, IsWindow, SetForegroundWindow},
+};
+
+use crate::{
+    check_owner_thread,
+    dpi::DpiAwarenessScope,
+    raw::{self, WideBuffers},
+    ConfigError, MessageBox, Options, Result,
+};
+
+/// A message box with its text and title encoded once, created by [prepare](MessageBox::prepare).
+///
+/// Showing it doesn't allocate (unless hooks are needed for options like [position](MessageBox::position)),
+/// so it's suited for showing the exact same message box repeatedly.
+pub struct PreparedMessageBox<T> {
+    msgbox: raw::MessageBox<T>,
+    /// The buffers `msgbox` points into. They're never modified,
+    /// so the pointers stay valid when the prepared message box is moved.
+    _buffers: WideBuffers,
+    /// The owner of `msgbox`, checked for [`strict_threading`](MessageBox::strict_threading).
+    hwnd: HWND,
+    /// Options the raw message box doesn't support, applied in [show](Self::show).
+    strict_threading: bool,
+    dpi_aware: bool,
+    restore_focus: bool,
+}
+
+impl<T> std::fmt::Debug for PreparedMessageBox<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Safety: `msgbox` points into `_buffers`.
+        let msgbox = unsafe { self.msgbox.debug_with_text() };
+        f.debug_tuple("PreparedMessageBox").field(&msgbox).finish()
+    }
+}
+
+impl<T: Options> PreparedMessageBox<T> {
+    /// Shows the message box, returning the option the user clicked on.
+    ///
+    /// See [`MessageBox::show`] for details.
+    pub fn show(&self) -> Result<T> {
+        check_owner_thread(self.hwnd, self.strict_threading)?;
+        let _dpi_scope = if self.dpi_aware {
+            DpiAwarenessScope::enter()
+        } else {
+            None
+        };
+        let previous_foreground = if self.restore_focus && self.hwnd.is_null() {
+            unsafe { GetForegroundWindow() }
+        } else {
+            std::ptr::null_mut()
+        };
+
+        // Safety: `msgbox` points into `_buffers`, which live as long as `self`.
+        let response = unsafe { self.msgbox.clone().show() };
+        if !previous_foreground.is_null() && unsafe { IsWindow(previous_foreground) } != 0 {
+            unsafe { SetForegroundWindow(previous_foreground) };
+        }
+        response
+    }
+}
+
+impl<T: Options> MessageBox<'_, T> {
+    /// Validates the message box and encodes it once, so it can be shown repeatedly without allocating.
+    ///
+    /// Returns the same errors as [validate](Self::validate).
+    /// Like [into_raw](Self::into_raw), an owner set through [`owner_by_title`](Self::owner_by_title)
+    /// and a custom [sound](Self::with_sound) aren't kept.
+    /// All other options, including [DPI awareness](Self::dpi_aware), [`restore_focus`](Self::restore_focus),
+    /// and [`strict_threading`](Self::strict_threading), apply whenever the prepared message box is shown.
+    ///
+    /// ```
+    /// # #[cfg(all(windows, feature = "testing"))]
+    /// # {
+    /// use win_msgbox::{testing, MessageBox, RetryCancel};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDRETRY};
+    ///
+    /// testing::push_response(IDRETRY);
+    /// testing::push_response(IDRETRY);
+    /// testing::push_response(IDCANCEL);
+    ///
+    /// let msgbox = MessageBox::<RetryCancel>::error("The device isn't ready.").prepare().unwrap();
+    /// let mut attempts = 1;
+    /// while msgbox.show() == Ok(RetryCancel::Retry) {
+    ///     attempts += 1;
+    /// }
+    /// assert_eq!(attempts, 3);
+    /// # }
+    /// ```
+    pub fn prepare(self) -> core::result::Result<PreparedMessageBox<T>, ConfigError> {
+        self.validate()?;
+        let hwnd = self.hwnd;
+        let strict_threading = self.strict_threading;
+        let dpi_aware = self.dpi_aware;
+        let restore_focus = self.restore_focus;
+        let (msgbox, buffers) = self.into_raw();
+        Ok(PreparedMessageBox {
+            msgbox,
+            _buffers: buffers,
+            hwnd,
+            strict_threading,
+            dpi_aware,
+            restore_focus,
+        })
+    }
+}
@@ -9,6 +9,7 @@
 //!
 //! To create a wide string statically, use the `w!` macro re-exported by this module from
 //! [`windows_sys`](https://docs.rs/windows-sys/latest/windows_sys/macro.w.html).
+//! For strings created at runtime, use [WideString], which [WideMessageBox] shows without `unsafe`.
 //!
 //! ## Examples
 //!
@@ -43,19 +44,34 @@
 //! ```
 #![deny(missing_docs)]
 #![deny(clippy::cargo)]
-use std::marker::PhantomData;
+use std::{
+    convert::Infallible,
+    ffi::OsStr,
+    iter::once,
+    marker::PhantomData,
+    rc::Rc,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 use windows_sys::{
     core::PCWSTR,
     Win32::{
-        Foundation::{GetLastError, HWND},
+        Foundation::{GetLastError, ERROR_INVALID_PARAMETER, HWND},
+        System::Diagnostics::Debug::MessageBeep,
         UI::WindowsAndMessaging::{
-            MessageBoxW, MB_DEFAULT_DESKTOP_ONLY, MB_HELP, MB_RIGHT, MB_RTLREADING,
-            MB_SERVICE_NOTIFICATION, MB_SETFOREGROUND, MB_TOPMOST, MESSAGEBOX_STYLE,
+            MB_DEFAULT_DESKTOP_ONLY, MB_HELP, MB_RIGHT, MB_RTLREADING, MB_SERVICE_NOTIFICATION,
+            MB_SETFOREGROUND, MB_TOPMOST, MESSAGEBOX_STYLE,
         },
     },
 };
 
-use crate::{DefaultButton, Icon, Modal, Options, Result};
+use crate::{
+    check_default_button,
+    hook::{CbtHook, DialogHooks, EscapeHook, OnCreated},
+    message_box_w, CustomIcon, DefaultButton, Icon, MessageBeepSound, Modal, MonitorSelector,
+    Options, Owner, Result,
+};
 
 pub use windows_sys::w;
 
@@ -64,14 +80,40 @@ pub use windows_sys::w;
 ///
 /// The type of the message box is specified by `T` (See [Options] for available options).
 pub struct MessageBox<T> {
-    /// The icon of this message box.
-    icon: Icon,
+    /// The icon of this message box (`None` if it has [no icon](Self::without_icon)).
+    icon: Option<Icon>,
+    /// The sound to play before showing the message box (default is None).
+    sound: Option<MessageBeepSound>,
     /// The text inside the message box.
     text: PCWSTR,
+    /// The buffer `text` points into if it was created by this message box (e.g. by [`from_wide_lines`](Self::from_wide_lines)).
+    owned_text: Option<Rc<[u16]>>,
     /// The title of the message box (default is null).
     title: PCWSTR,
     /// The owner window of the message box (default is `0` - no owner)
     hwnd: HWND,
+    /// The position of the message box in screen coordinates (default is None - centered).
+    position: Option<(i32, i32)>,
+    /// The monitor to center the message box on (default is None - centered on the owner or the screen).
+    monitor: Option<MonitorSelector>,
+    /// Whether to give the focus back to the previous foreground window (default is false).
+    keep_foreground: bool,
+    /// Whether to move the message box back to the top periodically (default is false).
+    keep_topmost: bool,
+    /// How long the message box fades in (default is None - shown immediately).
+    fade_in: Option<Duration>,
+    /// How long the buttons are disabled after the message box is shown (default is None).
+    min_display_time: Option<Duration>,
+    /// The language of the buttons (default is None - the language of the system).
+    language: Option<u16>,
+    /// Whether to close the message box when ESC is pressed (default is false).
+    escapable: bool,
+    /// Whether the message box must be task-modal without an owner (default is false).
+    thread_modal: bool,
+    /// The callback receiving the message box once it's created (default is None).
+    on_created: Option<OnCreated>,
+    /// The icon shown instead of the system icon, only set through [`into_raw`](crate::MessageBox::into_raw).
+    custom_icon: Option<CustomIcon>,
     /// Flags for the creation of this message box.
     flags: MESSAGEBOX_STYLE,
     /// The response options of message box.
@@ -87,6 +129,32 @@ impl<T> std::fmt::Debug for MessageBox<T> {
     }
 }
 
+impl<T> Clone for MessageBox<T> {
+    fn clone(&self) -> Self {
+        Self {
+            icon: self.icon,
+            sound: self.sound,
+            text: self.text,
+            owned_text: self.owned_text.clone(),
+            title: self.title,
+            hwnd: self.hwnd,
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            escapable: self.escapable,
+            thread_modal: self.thread_modal,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            language: self.language,
+            on_created: self.on_created.clone(),
+            custom_icon: self.custom_icon,
+            flags: self.flags,
+            _response: PhantomData,
+        }
+    }
+}
+
 macro_rules! ctors {
     ($($name:ident => $icon:ident),*) => {
         impl <T> MessageBox<T> {
@@ -111,41 +179,308 @@ impl<T> MessageBox<T> {
     /// you can separate the lines using a carriage return and/or linefeed character between each line.
     pub fn new(text: impl Into<PCWSTR>) -> Self {
         Self {
-            icon: Icon::Information,
+            icon: Some(Icon::Information),
+            sound: None,
             text: text.into(),
+            owned_text: None,
             title: std::ptr::null(),
             hwnd: std::ptr::null_mut(),
+            position: None,
+            monitor: None,
+            keep_foreground: false,
+            escapable: false,
+            thread_modal: false,
+            keep_topmost: false,
+            fade_in: None,
+            min_display_time: None,
+            language: None,
+            on_created: None,
+            custom_icon: None,
             flags: 0,
             _response: PhantomData,
         }
     }
 
+    /// Creates a new message box from a null-terminated UTF-16 `text`.
+    ///
+    /// Returns an error if `text` isn't null-terminated, contains a null before the terminator,
+    /// or contains an unpaired surrogate (which would be shown as a replacement glyph).
+    /// Note that only the pointer is stored, so `text` must still be alive when the message box is [shown](Self::show).
+    ///
+    /// ```
+    /// use win_msgbox::{raw::{FromWideError, MessageBox}, Okay};
+    ///
+    /// let text: Vec<u16> = "Hello".encode_utf16().chain(Some(0)).collect();
+    /// assert!(MessageBox::<Okay>::from_wide(&text).is_ok());
+    ///
+    /// // A surrogate pair
+    /// let emoji: Vec<u16> = "Done 🎉".encode_utf16().chain(Some(0)).collect();
+    /// assert!(MessageBox::<Okay>::from_wide(&emoji).is_ok());
+    ///
+    /// let unterminated: Vec<u16> = "Hello".encode_utf16().collect();
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::from_wide(&unterminated).unwrap_err(),
+    ///     FromWideError::MissingNullTerminator
+    /// );
+    ///
+    /// // A high surrogate without a low surrogate
+    /// let lone = [0x48, 0xD83C, 0x21, 0];
+    /// assert_eq!(
+    ///     MessageBox::<Okay>::from_wide(&lone).unwrap_err(),
+    ///     FromWideError::LoneSurrogate { position: 1 }
+    /// );
+    /// ```
+    pub fn from_wide(text: &[u16]) -> std::result::Result<Self, FromWideError> {
+        validate_wide(text)?;
+        validate_surrogates(text)?;
+        Ok(Self::new(text.as_ptr()))
+    }
+
+    /// Like [`from_wide`](Self::from_wide), but doesn't check for unpaired surrogates.
+    ///
+    /// The null terminator is still checked, as it's required to show the text safely.
+    ///
+    /// ```
+    /// use win_msgbox::{raw::MessageBox, Okay};
+    ///
+    /// let lone = [0x48, 0xD83C, 0x21, 0];
+    /// assert!(MessageBox::<Okay>::from_wide_unchecked(&lone).is_ok());
+    /// ```
+    pub fn from_wide_unchecked(text: &[u16]) -> std::result::Result<Self, FromWideError> {
+        validate_wide(text)?;
+        Ok(Self::new(text.as_ptr()))
+    }
+
+    /// Creates a new message box where the text consists of the UTF-16 `lines`,
+    /// separated by a carriage return and linefeed (`\r\n`).
+    ///
+    /// The lines shouldn't be null-terminated. They're joined into a null-terminated buffer owned by the message box,
+    /// so unlike [`new`](Self::new), they don't need to be alive when the message box is [shown](Self::show).
+    ///
+    /// ```
+    /// use win_msgbox::{raw::MessageBox, Okay};
+    ///
+    /// let first: Vec<u16> = "Download failed.".encode_utf16().collect();
+    /// let second: Vec<u16> = "Check your connection.".encode_utf16().collect();
+    /// let msgbox = MessageBox::<Okay>::from_wide_lines(&[&first, &second]);
+    ///
+    /// let expected: Vec<u16> = "Download failed.\r\nCheck your connection.\0".encode_utf16().collect();
+    /// let text = unsafe { std::slice::from_raw_parts(msgbox.text(), expected.len()) };
+    /// assert_eq!(text, expected);
+    /// ```
+    pub fn from_wide_lines(lines: &[&[u16]]) -> Self {
+        let text: Rc<[u16]> = lines
+            .join(&['\r' as u16, '\n' as u16][..])
+            .into_iter()
+            .chain(Some(0))
+            .collect();
+        let mut msgbox = Self::new(text.as_ptr());
+        msgbox.owned_text = Some(text);
+        msgbox
+    }
+
+    /// The pointer to the text of this message box.
+    pub fn text(&self) -> PCWSTR {
+        self.text
+    }
+
+    /// Reads the text and title back to format them like the [Debug] output of [`crate::MessageBox`].
+    ///
+    /// The regular [Debug] output doesn't include them, since it doesn't dereference the pointers.
+    /// Strings that aren't valid UTF-16 are converted lossily.
+    ///
+    /// ```
+    /// use win_msgbox::{
+    ///     raw::{w, MessageBox},
+    ///     Okay,
+    /// };
+    ///
+    /// let msgbox = MessageBox::<Okay>::new(w!("Hello")).title(w!("Greeting"));
+    /// // Safety: `w!` creates null-terminated UTF-16 strings.
+    /// let debug = format!("{:?}", unsafe { msgbox.debug_with_text() });
+    /// assert_eq!(
+    ///     debug,
+    ///     format!("{:?}", win_msgbox::MessageBox::<Okay>::new("Hello").title("Greeting"))
+    /// );
+    /// ```
+    ///
+    /// ### Safety
+    ///
+    /// [`text`][Self::new] and [`title`][Self::title] (if set) must point to a valid 16 bit, null terminated string.
+    pub unsafe fn debug_with_text(&self) -> impl std::fmt::Debug {
+        DebugWithText {
+            title: (!self.title.is_null()).then(|| read_wide(self.title)),
+            text: read_wide(self.text),
+            icon: self.icon,
+            hwnd: self.hwnd,
+        }
+    }
+
     /// The [Icon] to be displayed in this message box.
+    #[must_use]
     pub fn icon(mut self, icon: Icon) -> Self {
-        self.icon = icon;
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Shows the message box without an icon (the default is [Information](Icon::Information)).
+    ///
+    /// ```
+    /// use win_msgbox::{raw::{w, MessageBox}, Okay};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::MB_OK;
+    ///
+    /// let msgbox = MessageBox::<Okay>::new(w!("Hello")).without_icon();
+    /// assert_eq!(msgbox.style(), MB_OK);
+    /// ```
+    #[must_use]
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    /// Shows the message box without an icon, same as [`without_icon`](Self::without_icon).
+    ///
+    /// Win32 couples the sound of a message box to its icon,
+    /// so removing the icon also stops the system from playing a sound.
+    /// Use [`sound`](Self::sound) to play a sound regardless.
+    #[must_use]
+    pub fn silent(self) -> Self {
+        self.without_icon()
+    }
+
+    /// Plays a [MessageBeepSound] right before the message box is shown.
+    ///
+    /// This is independent of the [Icon] - if the message box has an icon,
+    /// the system will play the icon's sound in addition to this one.
+    /// Combine this with [`silent`](Self::silent) to only play this sound.
+    #[must_use]
+    pub fn sound(mut self, sound: MessageBeepSound) -> Self {
+        self.sound = Some(sound);
         self
     }
 
     /// The dialog box title. If this parameter is **null**, the default title is **Error**.
+    #[must_use]
     pub fn title(mut self, title: impl Into<PCWSTR>) -> Self {
         self.title = title.into();
         self
     }
 
-    /// A handle to the owner window of the message box to be created.
-    /// If this parameter is `0`, the message box has no owner window (default).
-    pub fn hwnd(mut self, hwnd: HWND) -> Self {
-        self.hwnd = hwnd;
+    /// The [Owner] window of the message box to be created.
+    /// If this is [`Owner::none`], the message box has no owner window (default).
+    ///
+    /// An owner can't be combined with [`service_notification`](Self::service_notification)
+    /// or [`task_modal_current_thread`](Self::task_modal_current_thread).
+    #[must_use]
+    pub fn hwnd(mut self, hwnd: impl Into<Owner>) -> Self {
+        self.hwnd = hwnd.into().hwnd();
+        self
+    }
+
+    /// Moves the message box to `x` and `y` (in screen coordinates) once it's shown.
+    ///
+    /// By default, the message box is centered on its owner or the screen.
+    /// Windows has no option to position a message box, so while it's shown,
+    /// a `WH_CBT` hook is installed on the current thread to move it once it's activated.
+    /// This relies on hooking and may be fragile across Windows versions.
+    #[must_use]
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Centers the message box on the selected monitor once it's shown,
+    /// e.g. to control where notifications without an owner appear.
+    ///
+    /// Like [`position`](Self::position), this installs a `WH_CBT` hook on the current thread
+    /// which moves the message box into the work area of the monitor once it's activated.
+    /// If the monitor doesn't exist, the message box isn't moved.
+    /// This is ignored if a [`position`](Self::position) is set.
+    #[must_use]
+    pub fn monitor(mut self, monitor: MonitorSelector) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Centers the message box on the monitor containing the cursor,
+    /// same as [`monitor(MonitorSelector::WithCursor)`](Self::monitor).
+    ///
+    /// Without an owner, message boxes are centered on the primary monitor,
+    /// which might not be the one the user is working on.
+    #[must_use]
+    pub fn center_on_screen(self) -> Self {
+        self.monitor(MonitorSelector::WithCursor)
+    }
+
+    /// Tries to keep the focus on the current foreground window instead of moving it to the message box,
+    /// e.g. for notifications shown from the background.
+    ///
+    /// Windows has no flag for this, so it's approximated: the foreground window is saved before the message box is shown,
+    /// and a `WH_CBT` hook gives it back the focus once the message box is activated.
+    /// The message box still flashes into the foreground briefly, and Windows may refuse to change the
+    /// foreground window (see [SetForegroundWindow](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-setforegroundwindow)).
+    #[must_use]
+    pub fn no_steal_focus(mut self) -> Self {
+        self.keep_foreground = true;
+        self
+    }
+
+    /// Closes the message box when ESC is pressed, even if it has no **Cancel** button.
+    ///
+    /// The message box returns `IDCANCEL`, which each option maps to:
+    ///
+    /// - [AbortRetryIgnore](crate::AbortRetryIgnore) → [`Ignore`](crate::AbortRetryIgnore::Ignore)
+    /// - [CancelTryAgainContinue](crate::CancelTryAgainContinue) → [`Cancel`](crate::CancelTryAgainContinue::Cancel)
+    /// - [Okay](crate::Okay) → [Okay](crate::Okay)
+    /// - [OkayCancel](crate::OkayCancel) → [`Cancel`](crate::OkayCancel::Cancel)
+    /// - [RetryCancel](crate::RetryCancel) → [`Cancel`](crate::RetryCancel::Cancel)
+    /// - [YesNo](crate::YesNo) → [`No`](crate::YesNo::No)
+    /// - [YesNoCancel](crate::YesNoCancel) → [`Cancel`](crate::YesNoCancel::Cancel)
+    ///
+    /// While the message box is shown, a `WH_KEYBOARD` hook is installed on the current thread.
+    #[must_use]
+    pub fn escapable(mut self) -> Self {
+        self.escapable = true;
+        self
+    }
+
+    /// Calls `callback` with the window of the message box once it's created,
+    /// e.g. to subclass it or add controls.
+    ///
+    /// Like [`position`](Self::position), this installs a `WH_CBT` hook on the current thread while the message box is shown.
+    /// The callback runs on the same thread once the message box is activated for the first time,
+    /// before it waits for input.
+    /// The window is provided as-is: modifying the message box is not supported by Windows
+    /// and may break in future versions.
+    ///
+    /// A panic in the callback can't unwind through the hook, so it's caught and the message box is shown regardless.
+    /// The callback isn't called again afterwards (including by clones of this message box).
+    #[must_use]
+    pub fn on_created(mut self, callback: impl FnMut(HWND) + Send + 'static) -> Self {
+        self.on_created = Some(Arc::new(Mutex::new(callback)));
         self
     }
 
     /// Set the modality of the dialog box. See [Modal] for options.
+    #[must_use]
     pub fn modal(mut self, modal: Modal) -> Self {
         self.flags |= modal as u32;
         self
     }
 
+    /// Makes the message box [task-modal](Modal::Task) without an owner,
+    /// so all top-level windows of the current thread are disabled while it's shown.
+    ///
+    /// Windows only disables the windows of the thread if there's no owner,
+    /// so [show](Self::show) returns `ERROR_INVALID_PARAMETER` if an [owner](Self::hwnd) is set.
+    #[must_use]
+    pub fn task_modal_current_thread(mut self) -> Self {
+        self.thread_modal = true;
+        self.modal(Modal::Task)
+    }
+
     /// Set the default button of the dialog box. See [DefaultButton] for options.
+    #[must_use]
     pub fn default_button(mut self, btn: DefaultButton) -> Self {
         self.flags |= btn as u32;
         self
@@ -154,18 +489,21 @@ impl<T> MessageBox<T> {
     /// Same as desktop of the interactive window station. For more information, see [Window Stations](https://learn.microsoft.com/windows/desktop/winstation/window-stations).
     /// If the current input desktop is not the default desktop,
     /// [show](Self::show) does not return until the user switches to the default desktop.
+    #[must_use]
     pub fn default_desktop_only(mut self) -> Self {
         self.flags |= MB_DEFAULT_DESKTOP_ONLY;
         self
     }
 
     /// The text is right-justified.
+    #[must_use]
     pub fn right(mut self) -> Self {
         self.flags |= MB_RIGHT;
         self
     }
 
     /// Displays message and caption text using right-to-left reading order on Hebrew and Arabic systems.
+    #[must_use]
     pub fn rtl_reading(mut self) -> Self {
         self.flags |= MB_RTLREADING;
         self
@@ -173,17 +511,61 @@ impl<T> MessageBox<T> {
 
     /// The message box becomes the foreground window.
     /// Internally, the system calls the [SetForegroundWindow](https://learn.microsoft.com/windows/desktop/api/winuser/nf-winuser-setforegroundwindow) function for the message box.
+    #[must_use]
     pub fn set_foreground(mut self) -> Self {
         self.flags |= MB_SETFOREGROUND;
         self
     }
 
     /// The message box is created with the `WS_EX_TOPMOST` window style.
+    #[must_use]
     pub fn topmost(mut self) -> Self {
         self.flags |= MB_TOPMOST;
         self
     }
 
+    /// Like [`topmost`](Self::topmost), but keeps the message box above other topmost windows,
+    /// e.g. for alerts in kiosk applications.
+    ///
+    /// Some shells and applications move their windows above a topmost message box.
+    /// To counter this, a timer moves the message box back to the top every 250 milliseconds while it's shown.
+    /// The timer is set up through a `WH_CBT` hook (like [`position`](Self::position))
+    /// and stops when the message box is closed. Its CPU cost is negligible, but it wakes up the thread regularly.
+    #[must_use]
+    pub fn keep_topmost(mut self) -> Self {
+        self.keep_topmost = true;
+        self.topmost()
+    }
+
+    /// Fades the message box in over `duration`.
+    ///
+    /// See [`MessageBox::fade_in`](crate::MessageBox::fade_in) for details.
+    #[must_use]
+    pub fn fade_in(mut self, duration: Duration) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+
+    /// Disables the buttons for `duration` after the message box is shown.
+    ///
+    /// See [`MessageBox::min_display_time`](crate::MessageBox::min_display_time) for details.
+    #[must_use]
+    pub fn min_display_time(mut self, duration: Duration) -> Self {
+        self.min_display_time = Some(duration);
+        self
+    }
+
+    /// Shows the text of the buttons in the language `language` through
+    /// [MessageBoxExW](https://learn.microsoft.com/windows/win32/api/winuser/nf-winuser-messageboxexw).
+    ///
+    /// `language` is a `LANGID` as created by `MAKELANGID(primary, sublanguage)`.
+    /// `0` uses the default language of the system. Other languages require the corresponding language pack.
+    #[must_use]
+    pub fn language(mut self, language: u16) -> Self {
+        self.language = Some(language);
+        self
+    }
+
     /// The caller is a service notifying the user of an event.
     /// The function displays a message box on the current active desktop,
     /// even if there is no user logged on to the computer.
@@ -191,12 +573,14 @@ impl<T> MessageBox<T> {
     /// Terminal Services: If the calling thread has an impersonation token,
     /// the function directs the message box to the session specified in the impersonation token.
     ///
-    /// If this is called, [`hwnd`](Self::hwnd) must not be called - it must remain `0`.
-    /// his is so that the message box can appear on a desktop other than the desktop corresponding to the `hwnd`.
+    /// If this is called, [`hwnd`](Self::hwnd) must not be called - it must remain [`Owner::none`],
+    /// otherwise [show](Self::show) returns `ERROR_INVALID_PARAMETER`.
+    /// This is so that the message box can appear on a desktop other than the desktop corresponding to the `hwnd`.
     ///
     /// For information on security considerations in regard to using this flag, see [Interactive Services](https://learn.microsoft.com/windows/desktop/Services/interactive-services).
     /// In particular, be aware that this flag can produce interactive content on a locked desktop
     /// and should therefore be used for only a very limited set of scenarios, such as resource exhaustion.
+    #[must_use]
     pub fn service_notification(mut self) -> Self {
         self.flags |= MB_SERVICE_NOTIFICATION;
         self
@@ -205,13 +589,67 @@ impl<T> MessageBox<T> {
     /// Adds a Help button to the message box.
     /// When the user clicks the Help button or presses F1,
     /// the system sends a [WM_HELP](https://learn.microsoft.com/windows/desktop/shell/wm-help) message to the owner.
+    #[must_use]
     pub fn with_help(mut self) -> Self {
         self.flags |= MB_HELP;
         self
     }
+
+    /// Adds raw `flags` to the style of the message box.
+    /// See [flags](crate::flags) for the available modifiers.
+    ///
+    /// The buttons are controlled by `T` and the icon by [`icon`](Self::icon).
+    /// Passing flags for either is the caller's responsibility and will likely produce an unexpected message box.
+    #[must_use]
+    pub fn with_flags(mut self, flags: MESSAGEBOX_STYLE) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Changes the buttons of the message box to `U` (See [Options] for available options),
+    /// keeping all other configuration.
+    #[must_use]
+    pub fn with_options<U>(self) -> MessageBox<U> {
+        MessageBox {
+            icon: self.icon,
+            sound: self.sound,
+            text: self.text,
+            owned_text: self.owned_text,
+            title: self.title,
+            hwnd: self.hwnd,
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            escapable: self.escapable,
+            thread_modal: self.thread_modal,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            language: self.language,
+            on_created: self.on_created,
+            custom_icon: self.custom_icon,
+            flags: self.flags,
+            _response: PhantomData,
+        }
+    }
 }
 
 impl<T: Options> MessageBox<T> {
+    /// The style passed to `MessageBoxW` when this message box is [shown](Self::show).
+    ///
+    /// ```
+    /// use win_msgbox::{raw::{w, MessageBox}, Okay};
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::{
+    ///     MB_ICONINFORMATION, MB_OK, MB_RIGHT, MB_TOPMOST,
+    /// };
+    ///
+    /// let msgbox = MessageBox::<Okay>::new(w!("Hello")).topmost().right();
+    /// assert_eq!(msgbox.style(), MB_OK | MB_ICONINFORMATION | MB_TOPMOST | MB_RIGHT);
+    /// ```
+    pub fn style(&self) -> MESSAGEBOX_STYLE {
+        T::flags() | self.icon.map_or(0, Icon::style) | self.flags
+    }
+
     /// Shows the message box, returning the option the user clicked on.
     ///
     /// If a message box has a **Cancel** button, the function returns the `Cancel` value
@@ -222,23 +660,390 @@ impl<T: Options> MessageBox<T> {
     ///
     /// If an **Ok** button is displayed and the user presses ESC, the return value will be `Ok`.
     ///
+    /// Returns `ERROR_INVALID_PARAMETER` if an [owner](Self::hwnd) is combined with
+    /// [`service_notification`](Self::service_notification) or [`task_modal_current_thread`](Self::task_modal_current_thread).
+    ///
+    /// Returns [`MsgBoxError::DefaultButtonOutOfRange`](crate::MsgBoxError::DefaultButtonOutOfRange)
+    /// if the [default button](Self::default_button) doesn't exist.
+    ///
     /// ### Safety
     ///
     /// [`text`][Self::new] and [`title`][Self::title] (if set) must point to a valid 16 bit, null terminated string.
     pub unsafe fn show(self) -> Result<T> {
-        let return_code = MessageBoxW(
+        if (self.flags & MB_SERVICE_NOTIFICATION != 0 || self.thread_modal) && !self.hwnd.is_null()
+        {
+            return Err(ERROR_INVALID_PARAMETER.into());
+        }
+        check_default_button::<T>(self.flags)?;
+
+        let _hook = CbtHook::for_dialog(DialogHooks {
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            custom_icon: self.custom_icon,
+            on_created: self.on_created.clone(),
+        })?;
+        let _escape_hook = if self.escapable {
+            Some(EscapeHook::install()?)
+        } else {
+            None
+        };
+
+        if let Some(sound) = self.sound {
+            // The sound is only cosmetic, so failing to play it isn't treated as an error.
+            MessageBeep(sound as u32);
+        }
+
+        let return_code = message_box_w(
             self.hwnd,
             self.text,
             self.title,
-            T::flags() | self.icon.style() | self.flags,
+            self.style(),
+            self.language,
         );
         match return_code {
-            0 => Err(GetLastError()),
+            0 => Err(GetLastError().into()),
             x => Ok(T::from(x)),
         }
     }
 }
 
+/// A [raw message box](MessageBox) with its text and title read back, created by [`MessageBox::debug_with_text`].
+struct DebugWithText {
+    title: Option<String>,
+    text: String,
+    icon: Option<Icon>,
+    hwnd: HWND,
+}
+
+impl std::fmt::Debug for DebugWithText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageBox")
+            .field("title", &self.title)
+            .field("text", &self.text)
+            .field("icon", &self.icon)
+            .field("hwnd", &self.hwnd)
+            .finish()
+    }
+}
+
+/// Reads the null-terminated UTF-16 string at `s`, replacing invalid sequences with `U+FFFD`.
+///
+/// ### Safety
+///
+/// `s` must point to a valid 16 bit, null terminated string.
+unsafe fn read_wide(s: PCWSTR) -> String {
+    let mut len = 0;
+    while *s.add(len) != 0 {
+        len += 1;
+    }
+    String::from_utf16_lossy(std::slice::from_raw_parts(s, len))
+}
+
+/// The UTF-16 text and title of a [`crate::MessageBox`] converted through [`into_raw`](crate::MessageBox::into_raw).
+///
+/// The [raw message box](MessageBox) points into these buffers,
+/// so they must be kept alive until it's [shown](MessageBox::show).
+#[cfg(feature = "safe")]
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct WideBuffers {
+    text: Vec<u16>,
+    title: Option<Vec<u16>>,
+}
+
+#[cfg(feature = "safe")]
+impl WideBuffers {
+    /// The null-terminated text.
+    pub fn text(&self) -> &[u16] {
+        &self.text
+    }
+
+    /// The null-terminated title, if one is set.
+    pub fn title(&self) -> Option<&[u16]> {
+        self.title.as_deref()
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<T> crate::MessageBox<'_, T> {
+    /// Encodes the text and title to UTF-16 and converts the message box to a [raw message box](MessageBox)
+    /// pointing into the returned buffers.
+    ///
+    /// The buffers must be kept alive until the raw message box is [shown](MessageBox::show).
+    /// Lines are normalized if [`normalize_newlines`](crate::MessageBox::normalize_newlines) is set.
+    /// An owner set through [`owner_by_title`](crate::MessageBox::owner_by_title) and a custom [sound](crate::MessageBox::with_sound) aren't converted, since the raw message box only supports [`hwnd`](MessageBox::hwnd) and [`sound`](MessageBox::sound).
+    /// [DPI awareness](crate::MessageBox::dpi_aware), [`restore_focus`](crate::MessageBox::restore_focus),
+    /// and [`strict_threading`](crate::MessageBox::strict_threading) aren't converted either
+    /// ([prepare](crate::MessageBox::prepare) keeps them).
+    ///
+    /// ```
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let (msgbox, buffers) = MessageBox::<Okay>::new("Hello").title("Greeting").into_raw();
+    /// assert_eq!(msgbox.text(), buffers.text().as_ptr());
+    /// assert_eq!(String::from_utf16_lossy(buffers.text()), "Hello\0");
+    ///
+    /// # #[cfg(all(windows, feature = "testing"))]
+    /// # {
+    /// # win_msgbox::testing::push_response(windows_sys::Win32::UI::WindowsAndMessaging::IDOK);
+    /// // Safety: `buffers` is still alive.
+    /// unsafe { msgbox.show() }.unwrap();
+    /// # }
+    /// drop(buffers);
+    /// ```
+    pub fn into_raw(self) -> (MessageBox<T>, WideBuffers) {
+        let flags = self.flags | self.direction_flags();
+        let mut text = Vec::new();
+        self.text.encode_into(&mut text, self.normalize_newlines);
+        let buffers = WideBuffers {
+            text,
+            title: self.title.as_ref().map(crate::text::Text::to_wide),
+        };
+        let msgbox = MessageBox {
+            // The system icon reserves the space for the custom icon.
+            icon: self.icon.or(self.custom_icon.map(|_| Icon::Information)),
+            sound: self.sound,
+            text: buffers.text.as_ptr(),
+            owned_text: None,
+            title: buffers
+                .title
+                .as_ref()
+                .map_or(std::ptr::null(), |title| title.as_ptr()),
+            hwnd: self.hwnd,
+            position: self.position,
+            monitor: self.monitor,
+            keep_foreground: self.keep_foreground,
+            keep_topmost: self.keep_topmost,
+            fade_in: self.fade_in,
+            min_display_time: self.min_display_time,
+            language: self.language,
+            escapable: self.escapable,
+            thread_modal: self.thread_modal,
+            on_created: self.on_created,
+            custom_icon: self.custom_icon,
+            flags,
+            _response: PhantomData,
+        };
+        (msgbox, buffers)
+    }
+}
+
+/// An error returned when validating a UTF-16 string in [`MessageBox::from_wide`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum FromWideError {
+    /// The last element of the string isn't `0`.
+    MissingNullTerminator,
+    /// The string contains a `0` before the terminator.
+    InteriorNull {
+        /// The index of the first `0`.
+        position: usize,
+    },
+    /// The string contains a surrogate that isn't part of a surrogate pair.
+    LoneSurrogate {
+        /// The index of the first unpaired surrogate.
+        position: usize,
+    },
+}
+
+impl std::fmt::Display for FromWideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingNullTerminator => f.write_str("the string isn't null-terminated"),
+            Self::InteriorNull { position } => {
+                write!(f, "the string contains a null at position {position}")
+            }
+            Self::LoneSurrogate { position } => {
+                write!(
+                    f,
+                    "the string contains an unpaired surrogate at position {position}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromWideError {}
+
+/// An owned, null-terminated UTF-16 string created at runtime.
+///
+/// Pass a reference to [`MessageBox::new`] or [`MessageBox::title`] to use it as the text or title.
+/// Only the pointer is stored, so the string must still be alive when the message box is [shown](MessageBox::show).
+/// To show a message box with a `WideString` without `unsafe`, use [WideMessageBox] or [show_wide].
+///
+/// A string containing a null (`\0`) is cut off at the first null when shown.
+///
+/// ```
+/// use std::str::FromStr;
+/// use win_msgbox::raw::WideString;
+///
+/// let text = WideString::from_str("Hello").unwrap();
+/// assert_eq!(text.as_wide(), [72, 101, 108, 108, 111, 0]);
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct WideString(Vec<u16>);
+
+impl WideString {
+    /// Encodes a string from the OS as UTF-16 without going through UTF-8 (on Windows).
+    pub fn from_osstr(s: &OsStr) -> Self {
+        Self(crate::text::Text::Os(s).to_wide())
+    }
+
+    /// The UTF-16 code units of this string, including the null terminator.
+    pub fn as_wide(&self) -> &[u16] {
+        &self.0
+    }
+
+    /// A pointer to the null-terminated string.
+    pub fn as_ptr(&self) -> PCWSTR {
+        self.0.as_ptr()
+    }
+}
+
+impl FromStr for WideString {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl From<&str> for WideString {
+    fn from(value: &str) -> Self {
+        Self(value.encode_utf16().chain(once(0)).collect())
+    }
+}
+
+impl From<&OsStr> for WideString {
+    fn from(value: &OsStr) -> Self {
+        Self::from_osstr(value)
+    }
+}
+
+impl From<&WideString> for PCWSTR {
+    fn from(value: &WideString) -> Self {
+        value.as_ptr()
+    }
+}
+
+/// A [raw message box](MessageBox) whose text and title are borrowed [WideString]s.
+///
+/// The strings are borrowed for `'a`, so they're guaranteed to be valid when the message box is shown,
+/// and unlike [`MessageBox::show`], [show](Self::show) is safe.
+/// Other options are set on the raw message box through [configure](Self::configure).
+///
+/// ```
+/// use win_msgbox::{raw::{WideMessageBox, WideString}, Icon, YesNo};
+///
+/// let text = WideString::from(format!("Delete {}?", "notes.txt").as_str());
+/// let title = WideString::from("Files");
+/// let msgbox = WideMessageBox::<YesNo>::new(&text)
+///     .title(&title)
+///     .configure(|msgbox| msgbox.icon(Icon::Question).topmost());
+/// # #[cfg(all(windows, feature = "testing"))]
+/// # {
+/// # win_msgbox::testing::push_response(windows_sys::Win32::UI::WindowsAndMessaging::IDYES);
+/// assert_eq!(msgbox.show(), Ok(YesNo::Yes));
+/// # }
+/// ```
+pub struct WideMessageBox<'a, T> {
+    /// The configuration, its text and title are replaced when shown.
+    msgbox: MessageBox<T>,
+    /// The text inside the message box.
+    text: &'a WideString,
+    /// The title of the message box (default is None).
+    title: Option<&'a WideString>,
+}
+
+impl<T> std::fmt::Debug for WideMessageBox<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WideMessageBox")
+            .field("title", &self.title)
+            .field("text", &self.text)
+            .field("icon", &self.msgbox.icon)
+            .field("hwnd", &self.msgbox.hwnd)
+            .finish()
+    }
+}
+
+impl<T> Clone for WideMessageBox<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            msgbox: self.msgbox.clone(),
+            text: self.text,
+            title: self.title,
+        }
+    }
+}
+
+impl<'a, T> WideMessageBox<'a, T> {
+    /// Creates a new message box with a specified `text` to be displayed.
+    pub fn new(text: &'a WideString) -> Self {
+        Self {
+            msgbox: MessageBox::new(text),
+            text,
+            title: None,
+        }
+    }
+
+    /// The dialog box title. If no title is set, the default title is **Error**.
+    #[must_use]
+    pub fn title(mut self, title: &'a WideString) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Configures the underlying [raw message box](MessageBox), e.g. its icon or owner.
+    ///
+    /// Setting the text or title there has no effect, since they're replaced by the borrowed strings when shown.
+    #[must_use]
+    pub fn configure(mut self, f: impl FnOnce(MessageBox<T>) -> MessageBox<T>) -> Self {
+        self.msgbox = f(self.msgbox);
+        self
+    }
+}
+
+impl<T: Options> WideMessageBox<'_, T> {
+    /// Shows the message box, returning the option the user clicked on.
+    ///
+    /// See [`MessageBox::show`] for details.
+    pub fn show(self) -> Result<T> {
+        let mut msgbox = self.msgbox;
+        msgbox.text = self.text.as_ptr();
+        msgbox.owned_text = None;
+        msgbox.title = self.title.map_or(std::ptr::null(), WideString::as_ptr);
+        // Safety: the text and title are null-terminated and borrowed until the message box is closed.
+        unsafe { msgbox.show() }
+    }
+}
+
+/// Checks that `s` is null-terminated and doesn't contain any other null.
+fn validate_wide(s: &[u16]) -> std::result::Result<(), FromWideError> {
+    match s.iter().position(|&c| c == 0) {
+        Some(position) if position + 1 == s.len() => Ok(()),
+        Some(position) => Err(FromWideError::InteriorNull { position }),
+        None => Err(FromWideError::MissingNullTerminator),
+    }
+}
+
+/// Checks that every surrogate in `s` is part of a surrogate pair.
+fn validate_surrogates(s: &[u16]) -> std::result::Result<(), FromWideError> {
+    let mut units = s.iter().enumerate();
+    while let Some((position, &unit)) = units.next() {
+        let paired = match unit {
+            0xD800..=0xDBFF => matches!(units.next(), Some((_, 0xDC00..=0xDFFF))),
+            0xDC00..=0xDFFF => false,
+            _ => true,
+        };
+        if !paired {
+            return Err(FromWideError::LoneSurrogate { position });
+        }
+    }
+    Ok(())
+}
+
 ctors! {
     exclamation => Exclamation,
     warning => Warning,
@@ -260,3 +1065,19 @@ ctors! {
 pub unsafe fn show<T: Options>(text: impl Into<PCWSTR>) -> Result<T> {
     MessageBox::new(text).show()
 }
+
+/// Shows a message box with a specified `text` to be displayed.
+///
+/// Unlike [show], this is safe, because `text` is guaranteed to be a valid, null-terminated string
+/// that lives until the message box is closed.
+/// For more options see [WideMessageBox].
+///
+/// ```no_run
+/// use win_msgbox::{raw::{show_wide, WideString}, Okay};
+///
+/// let name = std::env::args().next().unwrap_or_default();
+/// show_wide::<Okay>(&WideString::from(name.as_str())).unwrap();
+/// ```
+pub fn show_wide<T: Options>(text: &WideString) -> Result<T> {
+    WideMessageBox::new(text).show()
+}
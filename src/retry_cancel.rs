@@ -1,10 +1,15 @@
-use super::Options;
+#[cfg(feature = "safe")]
+use super::MessageBox;
+use super::{Confirmable, DefaultButton, Options, UnknownResult};
+#[cfg(feature = "safe")]
+use std::{thread, time::Duration};
 use windows_sys::Win32::UI::WindowsAndMessaging::{
-    IDRETRY, MB_RETRYCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDCANCEL, IDRETRY, MB_RETRYCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// The message box contains two push buttons: **Retry** and **Cancel**.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RetryCancel {
     /// The **Retry** button was selected.
     Retry,
@@ -12,6 +17,7 @@ pub enum RetryCancel {
     Cancel,
 }
 
+/// Unknown codes are mapped to [`Cancel`](RetryCancel::Cancel). Use [`try_from_code`](Self::try_from_code) to reject them.
 impl From<MESSAGEBOX_RESULT> for RetryCancel {
     fn from(value: MESSAGEBOX_RESULT) -> Self {
         match value {
@@ -21,8 +27,135 @@ impl From<MESSAGEBOX_RESULT> for RetryCancel {
     }
 }
 
+impl RetryCancel {
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDRETRY => Ok(Self::Retry),
+            IDCANCEL => Ok(Self::Cancel),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// [`Retry`](RetryCancel::Retry) is `true` and [`Cancel`](RetryCancel::Cancel) is `false`.
+///
+/// ```
+/// use win_msgbox::RetryCancel;
+///
+/// assert!(bool::from(RetryCancel::Retry));
+/// assert!(!bool::from(RetryCancel::Cancel));
+/// assert_eq!(RetryCancel::from(true), RetryCancel::Retry);
+/// assert_eq!(RetryCancel::from(false), RetryCancel::Cancel);
+/// ```
+impl From<RetryCancel> for bool {
+    fn from(value: RetryCancel) -> Self {
+        value == RetryCancel::Retry
+    }
+}
+
+/// `true` is [`Retry`](RetryCancel::Retry) and `false` is [`Cancel`](RetryCancel::Cancel).
+impl From<bool> for RetryCancel {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Retry
+        } else {
+            Self::Cancel
+        }
+    }
+}
+
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::RetryCancel;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDRETRY};
+///
+/// assert!(RetryCancel::Cancel == IDCANCEL);
+/// assert!(RetryCancel::Cancel != IDRETRY);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for RetryCancel {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        let code = match self {
+            Self::Retry => IDRETRY,
+            Self::Cancel => IDCANCEL,
+        };
+        *other == code
+    }
+}
+
 impl Options for RetryCancel {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_RETRYCANCEL
+    const FLAGS: MESSAGEBOX_STYLE = MB_RETRYCANCEL;
+    const CAPTIONS: &'static [&'static str] = &["Retry", "Cancel"];
+
+    fn safe_button() -> DefaultButton {
+        // Cancel
+        DefaultButton::DefaultButton2
+    }
+}
+
+/// [`Retry`](RetryCancel::Retry) is confirmed.
+impl Confirmable for RetryCancel {
+    fn is_confirmed(&self) -> bool {
+        *self == Self::Retry
+    }
+}
+
+impl std::fmt::Display for RetryCancel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Retry => "Retry",
+            Self::Cancel => "Cancel",
+        })
     }
 }
+
+#[cfg(feature = "safe")]
+impl MessageBox<'_, RetryCancel> {
+    /// Shows the message box and, if the user selected **Retry**, waits before returning
+    /// to avoid hammering a failing resource (e.g. a server).
+    ///
+    /// The delay grows exponentially with `attempt` (starting at `0`): it's `base * 2^attempt`, but at most `max`.
+    /// For example, with a `base` of 1s and a `max` of 30s, the delays are 1s, 2s, 4s, 8s, 16s, 30s, 30s, ...
+    /// The delay starts once the message box is closed, and the current thread is blocked while waiting.
+    /// If the user selected **Cancel** (or an error occurred), this returns immediately.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use win_msgbox::{MessageBox, RetryCancel};
+    ///
+    /// # fn download() -> std::io::Result<()> { Ok(()) }
+    /// let mut attempt = 0;
+    /// while let Err(e) = download() {
+    ///     let response = MessageBox::<RetryCancel>::error(format!("Download failed: {e}"))
+    ///         .show_retry_backoff(Duration::from_secs(1), Duration::from_secs(30), attempt)?;
+    ///     if response == RetryCancel::Cancel {
+    ///         break;
+    ///     }
+    ///     attempt += 1;
+    /// }
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    pub fn show_retry_backoff(
+        self,
+        base: Duration,
+        max: Duration,
+        attempt: u32,
+    ) -> crate::Result<RetryCancel> {
+        let response = self.show()?;
+        if response == RetryCancel::Retry {
+            thread::sleep(backoff_delay(base, max, attempt));
+        }
+        Ok(response)
+    }
+}
+
+/// `base * 2^attempt`, capped at `max`.
+#[cfg(feature = "safe")]
+fn backoff_delay(base: Duration, max: Duration, attempt: u32) -> Duration {
+    2u32.checked_pow(attempt)
+        .and_then(|factor| base.checked_mul(factor))
+        .map_or(max, |delay| delay.min(max))
+}
@@ -0,0 +1,333 @@
+use crate::{
+    hook::is_message_box, monitor::monitor_count, MessageBox, MonitorSelector, Options,
+    OwnedMessageBox, Result,
+};
+use std::{
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+use windows_sys::Win32::{
+    Foundation::{BOOL, FALSE, HWND, LPARAM, TRUE},
+    System::Threading::GetCurrentThreadId,
+    UI::WindowsAndMessaging::{EnumThreadWindows, PostMessageW, WM_CLOSE},
+};
+
+/// Closes a message box shown through [spawn_cancellable](MessageBox::spawn_cancellable) from another thread.
+#[derive(Debug, Clone)]
+pub struct DialogCloser {
+    /// The thread the message box is shown on.
+    thread_id: u32,
+}
+
+impl DialogCloser {
+    /// Posts `WM_CLOSE` to the message box, returning `true` if one was found.
+    ///
+    /// Closing a message box behaves like pressing ESC: message boxes with a **Cancel** button
+    /// return `Cancel` and message boxes with only an **OK** button return `Okay`.
+    /// Other message boxes ignore `WM_CLOSE` and stay open.
+    ///
+    /// This is inherently racy. If the message box isn't created yet, nothing is closed and `false` is returned.
+    /// If the user selects a button at the same moment, their selection may win.
+    /// To close a message box that was just spawned, retry until it's found or its thread finished:
+    ///
+    /// ```no_run
+    /// use std::{thread, time::Duration};
+    /// use win_msgbox::{MessageBox, OkayCancel};
+    ///
+    /// let (handle, closer) = MessageBox::<OkayCancel>::new("Connecting...").spawn_cancellable();
+    /// while !closer.close() && !handle.is_finished() {
+    ///     thread::sleep(Duration::from_millis(10));
+    /// }
+    /// let (_, response) = handle.join().unwrap();
+    /// assert!(response.is_ok());
+    /// ```
+    pub fn close(&self) -> bool {
+        let mut found = false;
+        unsafe {
+            EnumThreadWindows(
+                self.thread_id,
+                Some(close_message_box),
+                &mut found as *mut bool as LPARAM,
+            )
+        };
+        found
+    }
+}
+
+/// Posts `WM_CLOSE` to `hwnd` if it's a message box and stops the enumeration.
+///
+/// `lparam` points to a `bool` that's set to `true` if a message box was found.
+unsafe extern "system" fn close_message_box(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    if !is_message_box(hwnd) {
+        return TRUE;
+    }
+    PostMessageW(hwnd, WM_CLOSE, 0, 0);
+    *(lparam as *mut bool) = true;
+    FALSE
+}
+
+/// Moves a value to another thread.
+///
+/// The only `!Send` parts of a [MessageBox] are its owner `HWND` and its custom `HICON`,
+/// which are plain handles that can be used from any thread.
+struct AssertSend<T>(T);
+
+unsafe impl<T> Send for AssertSend<T> {}
+
+impl<T> AssertSend<T> {
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// The [id](MessageBox::id) of a message box and its response.
+type TaggedResult<T> = (Option<u64>, Result<T>);
+
+impl<T: Options + Send + 'static> MessageBox<'static, T> {
+    /// Shows the message box on a new thread.
+    ///
+    /// The returned [JoinHandle] resolves to the [id](MessageBox::id) of the message box and the response
+    /// once the message box is closed - either by the user or through the returned [DialogCloser].
+    ///
+    /// ```no_run
+    /// use std::{thread, time::Duration};
+    /// use win_msgbox::{MessageBox, OkayCancel};
+    ///
+    /// let (handle, closer) = MessageBox::<OkayCancel>::new("Closing in 5 seconds").spawn_cancellable();
+    /// thread::sleep(Duration::from_secs(5));
+    /// closer.close();
+    /// assert_eq!(handle.join().unwrap(), (None, Ok(OkayCancel::Cancel)));
+    /// ```
+    ///
+    /// Use [id](MessageBox::id) to tell the responses of multiple message boxes apart:
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, YesNo};
+    ///
+    /// let handles: Vec<_> = ["a.txt", "b.txt"]
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(i, file)| {
+    ///         let (handle, _) = MessageBox::<YesNo>::question(format!("Delete {file}?"))
+    ///             .id(i as u64)
+    ///             .spawn_cancellable();
+    ///         handle
+    ///     })
+    ///     .collect();
+    /// for handle in handles {
+    ///     if let (Some(id), Ok(YesNo::Yes)) = handle.join().unwrap() {
+    ///         println!("Deleting file {id}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the thread can't be created, like [`thread::spawn`].
+    pub fn spawn_cancellable(self) -> (JoinHandle<TaggedResult<T>>, DialogCloser) {
+        let (tx, rx) = mpsc::channel();
+        let msgbox = AssertSend(self);
+        let handle = thread::spawn(move || {
+            let msgbox = msgbox.into_inner();
+            // The receiver is alive until we sent the ID.
+            let _ = tx.send(unsafe { GetCurrentThreadId() });
+            (msgbox.id, msgbox.show())
+        });
+        // The thread sends its ID before anything that could panic.
+        let thread_id = rx
+            .recv()
+            .expect("the message box thread exited before sending its ID");
+        (handle, DialogCloser { thread_id })
+    }
+}
+
+/// Message boxes shown on their own threads at the same time, created by [new](Self::new).
+///
+/// Iterating yields the response of each message box once it's closed.
+/// The responses are in the order the message boxes are closed in, not in the order they were passed in.
+/// If a message box thread panics, its response is skipped.
+///
+/// ```no_run
+/// use win_msgbox::{DialogBatch, Okay, OwnedMessageBox};
+///
+/// let batch = DialogBatch::new(
+///     ["Build finished", "Tests passed", "Deployed"].map(OwnedMessageBox::<Okay>::new),
+/// );
+/// for response in batch {
+///     println!("A message box was closed: {response:?}");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DialogBatch<T> {
+    /// Receives the responses, disconnected once all threads exited.
+    responses: mpsc::Receiver<Result<T>>,
+}
+
+impl<T: Options + Send + 'static> DialogBatch<T> {
+    /// Shows each of the `boxes` on a new thread.
+    pub fn new(boxes: impl IntoIterator<Item = OwnedMessageBox<T>>) -> Self {
+        let (tx, rx) = mpsc::channel();
+        for msgbox in boxes {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                // The batch might have been dropped, then the response is discarded.
+                let _ = tx.send(msgbox.show());
+            });
+        }
+        Self { responses: rx }
+    }
+}
+
+impl<T> Iterator for DialogBatch<T> {
+    type Item = Result<T>;
+
+    /// Blocks until the next message box is closed, returning `None` once all of them are.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.responses.recv().ok()
+    }
+}
+
+/// A message box to be shown on another thread, created by [into_request](MessageBox::into_request).
+///
+/// The response is sent to the receiver returned alongside the request.
+pub struct MsgBoxRequest {
+    show: Box<dyn FnOnce() + Send>,
+}
+
+impl MsgBoxRequest {
+    /// Shows the message box on the current thread, blocking until it's closed,
+    /// and sends the response to the receiver.
+    ///
+    /// If the receiver was dropped, the response is discarded.
+    pub fn show_now(self) {
+        (self.show)()
+    }
+}
+
+impl std::fmt::Debug for MsgBoxRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MsgBoxRequest").finish_non_exhaustive()
+    }
+}
+
+impl<T: Options + Send + 'static> MessageBox<'static, T> {
+    /// Converts the message box into a request that can be shown on another thread,
+    /// e.g. the UI thread of a GUI framework, and a receiver for the response.
+    ///
+    /// The intended pattern is to create message boxes anywhere, send the requests to the UI thread
+    /// (e.g. through a channel or the event loop of the framework), and call [show_now](MsgBoxRequest::show_now)
+    /// there once it's safe to block. The code that created the message box polls
+    /// (or blocks on) the receiver for the response.
+    ///
+    /// ```no_run
+    /// use std::sync::mpsc;
+    /// use win_msgbox::{MessageBox, MsgBoxRequest, YesNo};
+    ///
+    /// let (requests, ui_queue) = mpsc::channel::<MsgBoxRequest>();
+    ///
+    /// // Anywhere in the application:
+    /// let (request, response) = MessageBox::<YesNo>::question("Save changes?").into_request();
+    /// requests.send(request).unwrap();
+    ///
+    /// // On the UI thread, e.g. once per frame:
+    /// for request in ui_queue.try_iter() {
+    ///     request.show_now();
+    /// }
+    ///
+    /// // Back where the message box was created:
+    /// if let Ok(Ok(YesNo::Yes)) = response.try_recv() {
+    ///     println!("Saving...");
+    /// }
+    /// ```
+    pub fn into_request(self) -> (MsgBoxRequest, mpsc::Receiver<Result<T>>) {
+        let (tx, rx) = mpsc::channel();
+        let msgbox = AssertSend(self);
+        let request = MsgBoxRequest {
+            show: Box::new(move || {
+                // The requester might not be interested in the response anymore.
+                let _ = tx.send(msgbox.into_inner().show());
+            }),
+        };
+        (request, rx)
+    }
+}
+
+impl<T: Options + Send> MessageBox<'_, T> {
+    /// Shows the message box on every monitor at the same time (e.g. for alerts on digital signage),
+    /// returning the responses in the order of the monitors.
+    ///
+    /// Every message box is shown on its own thread and centered on its [monitor](Self::monitor)
+    /// (a [position](Self::position) is ignored). The message boxes are independent of each other:
+    /// each one has to be closed separately, and the current thread is blocked until all of them are closed.
+    /// If showing any of the message boxes failed, the first error (in the order of the monitors) is returned.
+    ///
+    /// Since the message boxes are shown on other threads, an [owner](Self::hwnd) isn't disabled while they're shown
+    /// (and [`strict_threading`](Self::strict_threading) makes them fail).
+    /// If there are no monitors (e.g. in a service), no message box is shown and the result is empty.
+    ///
+    /// ```no_run
+    /// use win_msgbox::{MessageBox, Okay};
+    ///
+    /// let responses = MessageBox::<Okay>::warning("The building will close in 10 minutes.")
+    ///     .topmost()
+    ///     .show_on_all_monitors()?;
+    /// println!("Acknowledged on {} monitors", responses.len());
+    /// # Ok::<(), win_msgbox::MsgBoxError>(())
+    /// ```
+    pub fn show_on_all_monitors(self) -> Result<Vec<T>> {
+        let count = monitor_count();
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..count)
+                .map(|index| {
+                    let mut msgbox = self.clone().monitor(MonitorSelector::Index(index));
+                    msgbox.position = None;
+                    let msgbox = AssertSend(msgbox);
+                    scope.spawn(move || msgbox.into_inner().show())
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(feature = "com")]
+impl<T: Options + Send> MessageBox<'_, T> {
+    /// Shows the message box on a new thread that's initialized as a single-threaded COM apartment (STA),
+    /// blocking until the message box is closed.
+    ///
+    /// This is mainly relevant for [`service_notification`](Self::service_notification)
+    /// and shell-hosted scenarios, where some integrations misbehave outside of an STA.
+    ///
+    /// Returns [`MsgBoxError::Com`](crate::MsgBoxError::Com) if COM couldn't be initialized.
+    pub fn show_on_thread_with_sta(self) -> Result<T> {
+        use crate::MsgBoxError;
+        use windows_sys::Win32::System::Com::{
+            CoInitializeEx, CoUninitialize, COINIT_APARTMENTTHREADED,
+        };
+
+        let msgbox = AssertSend(self);
+        thread::scope(|scope| {
+            let handle = scope.spawn(move || {
+                let msgbox = msgbox.into_inner();
+                let hr =
+                    unsafe { CoInitializeEx(std::ptr::null(), COINIT_APARTMENTTHREADED as u32) };
+                if hr < 0 {
+                    return Err(MsgBoxError::Com(hr));
+                }
+                let response = msgbox.show();
+                unsafe { CoUninitialize() };
+                response
+            });
+            handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    }
+}
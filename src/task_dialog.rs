@@ -0,0 +1,203 @@
+use crate::{text::Text, Icon, MsgBoxError, Owner, Result};
+use std::{borrow::Cow, sync::OnceLock};
+use windows_sys::{
+    core::HRESULT,
+    s, w,
+    Win32::{
+        Foundation::{BOOL, ERROR_PROC_NOT_FOUND, HWND},
+        System::LibraryLoader::{GetProcAddress, LoadLibraryW},
+        UI::Controls::{
+            TASKDIALOGCONFIG, TASKDIALOG_BUTTON, TDF_ALLOW_DIALOG_CANCELLATION,
+            TDF_POSITION_RELATIVE_TO_WINDOW, TDF_USE_HICON_MAIN, TD_ERROR_ICON,
+            TD_INFORMATION_ICON, TD_WARNING_ICON,
+        },
+    },
+};
+
+/// The signature of `TaskDialogIndirect`.
+type TaskDialogIndirectFn =
+    unsafe extern "system" fn(*const TASKDIALOGCONFIG, *mut i32, *mut i32, *mut BOOL) -> HRESULT;
+
+/// Loads `TaskDialogIndirect` from comctl32 once per process.
+///
+/// It's only exported by version 6 of the common controls, so it's loaded at runtime:
+/// linking to it would prevent executables without the manifest from starting,
+/// even if they never show a task dialog.
+fn task_dialog_indirect() -> Option<TaskDialogIndirectFn> {
+    static TASK_DIALOG_INDIRECT: OnceLock<Option<TaskDialogIndirectFn>> = OnceLock::new();
+    *TASK_DIALOG_INDIRECT.get_or_init(|| unsafe {
+        // The activation context of the manifest selects the version of comctl32 that's loaded.
+        // The library is never freed, since the function is cached.
+        let comctl32 = LoadLibraryW(w!("comctl32.dll"));
+        if comctl32.is_null() {
+            return None;
+        }
+        let proc = GetProcAddress(comctl32, s!("TaskDialogIndirect"))?;
+        Some(std::mem::transmute::<
+            unsafe extern "system" fn() -> isize,
+            TaskDialogIndirectFn,
+        >(proc))
+    })
+}
+
+/// A builder for a task dialog, which supports buttons with custom captions.
+///
+/// Unlike [MessageBox](crate::MessageBox), the buttons aren't fixed: add them with [`add_button`](Self::add_button)
+/// and match on the returned ID. Without any button, the dialog shows an **OK** button (`IDOK = 1`).
+/// Closing the dialog (e.g. by pressing ESC) returns `IDCANCEL` (`2`), so avoid using `2` for other buttons.
+///
+/// Task dialogs require version 6 of the common controls, which must be enabled through an
+/// [application manifest](https://learn.microsoft.com/windows/win32/controls/cookbook-overview).
+/// Without it, [show](Self::show) returns [`MsgBoxError::TaskDialog`].
+///
+/// ```no_run
+/// use win_msgbox::{Icon, TaskDialog};
+///
+/// const SAVE: i32 = 100;
+/// const DISCARD: i32 = 101;
+///
+/// # fn main() -> win_msgbox::Result<()> {
+/// let clicked = TaskDialog::new("Your changes will be lost if you don't save them.")
+///     .title("Editor")
+///     .instruction("Save changes to notes.txt?")
+///     .icon(Icon::Warning)
+///     .add_button(SAVE, "Save")
+///     .add_button(DISCARD, "Don't save")
+///     .show()?;
+/// if clicked == SAVE {
+///     println!("Saving...");
+/// }
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskDialog<'a> {
+    /// The text below the main instruction.
+    content: Cow<'a, str>,
+    /// The title of the dialog (default is None - the executable name).
+    title: Option<Cow<'a, str>>,
+    /// The large text above the content (default is None).
+    instruction: Option<Cow<'a, str>>,
+    /// The icon next to the main instruction (default is None).
+    icon: Option<Icon>,
+    /// The custom buttons and their IDs.
+    buttons: Vec<(i32, Cow<'a, str>)>,
+    /// The owner window of the dialog (default is `0` - no owner)
+    hwnd: HWND,
+}
+
+impl<'a> TaskDialog<'a> {
+    /// Creates a new task dialog with a specified `content` to be displayed.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            title: None,
+            instruction: None,
+            icon: None,
+            buttons: Vec::new(),
+            hwnd: std::ptr::null_mut(),
+        }
+    }
+
+    /// The title of the dialog.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The main instruction, shown in a larger font above the content.
+    #[must_use]
+    pub fn instruction(mut self, instruction: impl Into<Cow<'a, str>>) -> Self {
+        self.instruction = Some(instruction.into());
+        self
+    }
+
+    /// The [Icon] to be displayed next to the main instruction.
+    ///
+    /// Icons that look the same (see [`Icon::canonical`]) are shown the same.
+    #[must_use]
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Adds a button with a custom `caption`. [show](Self::show) returns `id` if it's clicked.
+    ///
+    /// Buttons are shown in the order they're added.
+    #[must_use]
+    pub fn add_button(mut self, id: i32, caption: impl Into<Cow<'a, str>>) -> Self {
+        self.buttons.push((id, caption.into()));
+        self
+    }
+
+    /// The [Owner] window of the dialog. The dialog is centered on its owner.
+    #[must_use]
+    pub fn hwnd(mut self, hwnd: impl Into<Owner>) -> Self {
+        self.hwnd = hwnd.into().hwnd();
+        self
+    }
+
+    /// Shows the dialog, returning the ID of the button the user clicked on.
+    ///
+    /// Returns [`MsgBoxError::TaskDialog`] if `TaskDialogIndirect` failed or isn't available
+    /// (with `HRESULT_FROM_WIN32(ERROR_PROC_NOT_FOUND)`) because version 6 of the common controls isn't enabled.
+    pub fn show(&self) -> Result<i32> {
+        let Some(task_dialog_indirect) = task_dialog_indirect() else {
+            // HRESULT_FROM_WIN32
+            let hr = (ERROR_PROC_NOT_FOUND & 0xFFFF | 0x8007_0000) as HRESULT;
+            return Err(MsgBoxError::TaskDialog(hr));
+        };
+        let wide = |s: &Cow<'a, str>| Text::Str(Cow::Borrowed(s)).to_wide();
+        let content = wide(&self.content);
+        let title = self.title.as_ref().map(wide);
+        let instruction = self.instruction.as_ref().map(wide);
+        let captions: Vec<_> = self.buttons.iter().map(|(_, c)| wide(c)).collect();
+        let buttons: Vec<_> = self
+            .buttons
+            .iter()
+            .zip(&captions)
+            .map(|((id, _), caption)| TASKDIALOG_BUTTON {
+                nButtonID: *id,
+                pszButtonText: caption.as_ptr(),
+            })
+            .collect();
+
+        let mut config: TASKDIALOGCONFIG = unsafe { std::mem::zeroed() };
+        config.cbSize = std::mem::size_of::<TASKDIALOGCONFIG>() as u32;
+        config.hwndParent = self.hwnd;
+        config.dwFlags = TDF_ALLOW_DIALOG_CANCELLATION | TDF_POSITION_RELATIVE_TO_WINDOW;
+        config.pszWindowTitle = title.as_ref().map_or(std::ptr::null(), |t| t.as_ptr());
+        config.pszMainInstruction = instruction
+            .as_ref()
+            .map_or(std::ptr::null(), |i| i.as_ptr());
+        config.pszContent = content.as_ptr();
+        config.cButtons = buttons.len() as u32;
+        config.pButtons = buttons.as_ptr();
+        match self.icon.map(Icon::canonical) {
+            Some(Icon::Error) => config.Anonymous1.pszMainIcon = TD_ERROR_ICON,
+            Some(Icon::Warning) => config.Anonymous1.pszMainIcon = TD_WARNING_ICON,
+            Some(Icon::Question) => {
+                // There's no built-in task dialog icon for questions.
+                config.dwFlags |= TDF_USE_HICON_MAIN;
+                config.Anonymous1.hMainIcon = Icon::Question.to_hicon();
+            }
+            Some(_) => config.Anonymous1.pszMainIcon = TD_INFORMATION_ICON,
+            None => (),
+        }
+
+        let mut clicked = 0;
+        let hr = unsafe {
+            task_dialog_indirect(
+                &config,
+                &mut clicked,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        if hr < 0 {
+            return Err(MsgBoxError::TaskDialog(hr));
+        }
+        Ok(clicked)
+    }
+}
@@ -0,0 +1,36 @@
+//! Canned responses for testing code that shows message boxes (enabled by the `testing` feature).
+//!
+//! Responses pushed through [push_response] are returned by the next [show][crate::MessageBox::show]
+//! on the same thread instead of showing a message box, in the order they were pushed.
+//! If no response is queued, the real message box is shown.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use win_msgbox::{testing, YesNo};
+//! use windows_sys::Win32::UI::WindowsAndMessaging::IDNO;
+//!
+//! testing::push_response(IDNO);
+//! assert_eq!(win_msgbox::show::<YesNo>("Delete everything?"), Ok(YesNo::No));
+//! ```
+use std::{cell::RefCell, collections::VecDeque};
+use windows_sys::Win32::UI::WindowsAndMessaging::MESSAGEBOX_RESULT;
+
+thread_local! {
+    static RESPONSES: RefCell<VecDeque<MESSAGEBOX_RESULT>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// Queues `code` to be returned by the next message box shown on this thread.
+pub fn push_response(code: MESSAGEBOX_RESULT) {
+    RESPONSES.with_borrow_mut(|responses| responses.push_back(code));
+}
+
+/// Removes all queued responses on this thread.
+pub fn clear_responses() {
+    RESPONSES.with_borrow_mut(VecDeque::clear);
+}
+
+/// Takes the next queued response on this thread.
+pub(crate) fn pop_response() -> Option<MESSAGEBOX_RESULT> {
+    RESPONSES.with_borrow_mut(VecDeque::pop_front)
+}
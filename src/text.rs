@@ -0,0 +1,163 @@
+// Most conversions are only used by the safe message box.
+#![cfg_attr(not(feature = "safe"), allow(dead_code))]
+
+use std::{borrow::Cow, ffi::OsStr};
+
+/// The text or title of a message box.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Text<'a> {
+    /// A UTF-8 string.
+    Str(Cow<'a, str>),
+    /// A string from the OS, which isn't necessarily valid UTF-8 (e.g. a file name).
+    Os(&'a OsStr),
+}
+
+impl Text<'_> {
+    /// Encodes the text as null-terminated UTF-16.
+    pub(crate) fn to_wide(&self) -> Vec<u16> {
+        let mut wide = Vec::new();
+        self.encode_into(&mut wide, false);
+        wide
+    }
+
+    /// Appends the text as null-terminated UTF-16 to `buf`,
+    /// replacing lone line feeds and carriage returns with `\r\n` if `normalize` is set.
+    pub(crate) fn encode_into(&self, buf: &mut Vec<u16>, normalize: bool) {
+        let cr = u16::from(b'\r');
+        let lf = u16::from(b'\n');
+        match (self, normalize) {
+            (Self::Str(s), false) => buf.extend(s.encode_utf16()),
+            (Self::Str(s), true) => normalize_newlines_into(s.encode_utf16(), cr, lf, buf),
+            (Self::Os(s), false) => buf.extend(encode_os(s)),
+            (Self::Os(s), true) => normalize_newlines_into(encode_os(s), cr, lf, buf),
+        }
+        buf.push(0);
+    }
+
+    /// Checks if the text contains more Hebrew or Arabic letters than letters of other scripts.
+    pub(crate) fn is_rtl(&self) -> bool {
+        let text = match self {
+            Self::Str(s) => Cow::Borrowed(s.as_ref()),
+            Self::Os(s) => s.to_string_lossy(),
+        };
+        let (rtl, ltr) =
+            text.chars()
+                .filter(|c| c.is_alphabetic())
+                .fold((0usize, 0usize), |(rtl, ltr), c| {
+                    if is_rtl_char(c) {
+                        (rtl + 1, ltr)
+                    } else {
+                        (rtl, ltr + 1)
+                    }
+                });
+        rtl > ltr
+    }
+
+    /// Converts the text to UTF-8, replacing invalid sequences with `U+FFFD`.
+    pub(crate) fn to_string_lossy(&self) -> Cow<'_, str> {
+        match self {
+            Self::Str(s) => Cow::Borrowed(s),
+            Self::Os(s) => s.to_string_lossy(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Text<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Str(s) => s.fmt(f),
+            Self::Os(s) => s.fmt(f),
+        }
+    }
+}
+
+/// Checks if `c` is in one of the Hebrew or Arabic blocks.
+fn is_rtl_char(c: char) -> bool {
+    matches!(
+        c,
+        // Hebrew, Arabic, Syriac, Arabic Supplement, Thaana, NKo, Samaritan, Mandaic, Arabic Extended
+        '\u{0590}'..='\u{08FF}'
+            // Hebrew and Arabic Presentation Forms-A
+            | '\u{FB1D}'..='\u{FDFF}'
+            // Arabic Presentation Forms-B
+            | '\u{FE70}'..='\u{FEFF}'
+    )
+}
+
+/// Replaces lone line feeds (`lf`) and carriage returns (`cr`) with `cr` `lf`,
+/// keeping existing `cr` `lf` sequences.
+pub(crate) fn normalize_newlines<C: Copy + Eq>(
+    units: impl IntoIterator<Item = C>,
+    cr: C,
+    lf: C,
+) -> Vec<C> {
+    let mut normalized = Vec::new();
+    normalize_newlines_into(units, cr, lf, &mut normalized);
+    normalized
+}
+
+/// Like [normalize_newlines], but appends to `normalized`.
+fn normalize_newlines_into<C: Copy + Eq>(
+    units: impl IntoIterator<Item = C>,
+    cr: C,
+    lf: C,
+    normalized: &mut Vec<C>,
+) {
+    let mut units = units.into_iter().peekable();
+    normalized.reserve(units.size_hint().0);
+    while let Some(unit) = units.next() {
+        if unit == cr || unit == lf {
+            if unit == cr {
+                units.next_if_eq(&lf);
+            }
+            normalized.extend([cr, lf]);
+        } else {
+            normalized.push(unit);
+        }
+    }
+}
+
+/// Wraps `text` at spaces into lines of at most `width` characters, separated by `\r\n`.
+///
+/// Newlines in `text` are kept. A word longer than `width` is put on a line of its own.
+pub(crate) fn wrap(text: &str, width: usize) -> String {
+    let mut wrapped = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            wrapped.push_str("\r\n");
+        }
+        let mut column = 0;
+        for (j, word) in line.trim_end_matches('\r').split(' ').enumerate() {
+            let len = word.chars().count();
+            if j > 0 {
+                if column > 0 && column + 1 + len > width {
+                    wrapped.push_str("\r\n");
+                    column = 0;
+                } else {
+                    wrapped.push(' ');
+                    column += 1;
+                }
+            }
+            wrapped.push_str(word);
+            column += len;
+        }
+    }
+    wrapped
+}
+
+/// Encodes `s` as UTF-16 without going through UTF-8.
+#[cfg(windows)]
+fn encode_os(s: &OsStr) -> impl Iterator<Item = u16> + '_ {
+    use std::os::windows::ffi::OsStrExt;
+    s.encode_wide()
+}
+
+/// Encodes `s` as UTF-16.
+/// Outside of Windows, `OsStr`s aren't UTF-16 based, so this goes through UTF-8.
+#[cfg(not(windows))]
+fn encode_os(s: &OsStr) -> impl Iterator<Item = u16> {
+    s.to_string_lossy()
+        .encode_utf16()
+        .collect::<Vec<_>>()
+        .into_iter()
+}
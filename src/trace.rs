@@ -0,0 +1,39 @@
+use crate::Result;
+use std::time::Instant;
+use tracing::{field::Empty, span::EnteredSpan};
+use windows_sys::Win32::UI::WindowsAndMessaging::MESSAGEBOX_RESULT;
+
+/// A `msgbox` span that's entered while a message box is shown.
+pub(crate) struct DialogSpan {
+    span: EnteredSpan,
+    opened: Instant,
+}
+
+impl DialogSpan {
+    /// Creates and enters a span for a message box with buttons `T`.
+    pub(crate) fn enter<T>(title: Option<&str>) -> Self {
+        let span = tracing::info_span!(
+            "msgbox",
+            title,
+            options = std::any::type_name::<T>(),
+            return_code = Empty,
+            error = Empty,
+            elapsed_ms = Empty,
+        )
+        .entered();
+        Self {
+            span,
+            opened: Instant::now(),
+        }
+    }
+
+    /// Records the outcome and the time the message box was open, then exits the span.
+    pub(crate) fn finish(self, response: Result<MESSAGEBOX_RESULT>) {
+        self.span
+            .record("elapsed_ms", self.opened.elapsed().as_millis() as u64);
+        match response {
+            Ok(code) => self.span.record("return_code", code),
+            Err(e) => self.span.record("error", tracing::field::display(e)),
+        };
+    }
+}
@@ -1,10 +1,11 @@
-use super::Options;
+use super::{Confirmable, DefaultButton, Options, UnknownResult};
 use windows_sys::Win32::UI::WindowsAndMessaging::{
-    IDYES, MB_YESNO, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDNO, IDYES, MB_YESNO, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// The message box contains two push buttons: **Yes** and **No**.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YesNo {
     /// The **Yes** button was selected.
     Yes,
@@ -12,6 +13,7 @@ pub enum YesNo {
     No,
 }
 
+/// Unknown codes are mapped to [`No`](YesNo::No). Use [`try_from_code`](Self::try_from_code) to reject them.
 impl From<MESSAGEBOX_RESULT> for YesNo {
     fn from(value: MESSAGEBOX_RESULT) -> Self {
         match value {
@@ -21,8 +23,87 @@ impl From<MESSAGEBOX_RESULT> for YesNo {
     }
 }
 
+impl YesNo {
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDYES => Ok(Self::Yes),
+            IDNO => Ok(Self::No),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// [`Yes`](YesNo::Yes) is `true` and [`No`](YesNo::No) is `false`.
+///
+/// ```
+/// use win_msgbox::YesNo;
+///
+/// assert!(bool::from(YesNo::Yes));
+/// assert!(!bool::from(YesNo::No));
+/// assert_eq!(YesNo::from(true), YesNo::Yes);
+/// assert_eq!(YesNo::from(false), YesNo::No);
+/// ```
+impl From<YesNo> for bool {
+    fn from(value: YesNo) -> Self {
+        value == YesNo::Yes
+    }
+}
+
+/// `true` is [`Yes`](YesNo::Yes) and `false` is [`No`](YesNo::No).
+impl From<bool> for YesNo {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Yes
+        } else {
+            Self::No
+        }
+    }
+}
+
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::YesNo;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDNO, IDYES};
+///
+/// assert!(YesNo::No == IDNO);
+/// assert!(YesNo::No != IDYES);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for YesNo {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        let code = match self {
+            Self::Yes => IDYES,
+            Self::No => IDNO,
+        };
+        *other == code
+    }
+}
+
 impl Options for YesNo {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_YESNO
+    const FLAGS: MESSAGEBOX_STYLE = MB_YESNO;
+    const CAPTIONS: &'static [&'static str] = &["Yes", "No"];
+
+    fn safe_button() -> DefaultButton {
+        // No
+        DefaultButton::DefaultButton2
+    }
+}
+
+/// [`Yes`](YesNo::Yes) is confirmed.
+impl Confirmable for YesNo {
+    fn is_confirmed(&self) -> bool {
+        *self == Self::Yes
+    }
+}
+
+impl std::fmt::Display for YesNo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Yes => "Yes",
+            Self::No => "No",
+        })
     }
 }
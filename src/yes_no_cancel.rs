@@ -1,10 +1,11 @@
-use super::Options;
+use super::{Confirmable, DefaultButton, Options, UnknownResult};
 use windows_sys::Win32::UI::WindowsAndMessaging::{
-    IDNO, IDYES, MB_YESNOCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDCANCEL, IDNO, IDYES, MB_YESNOCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// The message box contains three push buttons: **Yes**, **No**, and **Cancel**.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YesNoCancel {
     /// The **Yes** button was selected.
     Yes,
@@ -14,6 +15,35 @@ pub enum YesNoCancel {
     Cancel,
 }
 
+impl YesNoCancel {
+    /// Returns `true` if the **Cancel** button was selected or ESC was pressed.
+    ///
+    /// Pressing ESC in this message box returns [`Cancel`](Self::Cancel).
+    ///
+    /// ```
+    /// use win_msgbox::YesNoCancel;
+    /// use windows_sys::Win32::UI::WindowsAndMessaging::IDCANCEL;
+    ///
+    /// assert!(YesNoCancel::from(IDCANCEL).cancelled());
+    /// ```
+    pub fn cancelled(self) -> bool {
+        self == Self::Cancel
+    }
+
+    /// Converts the raw value returned by `MessageBoxW`, rejecting codes this option can't produce.
+    ///
+    /// Unlike the [From] implementation, this doesn't map unknown codes to a fallback.
+    pub fn try_from_code(value: MESSAGEBOX_RESULT) -> Result<Self, UnknownResult> {
+        match value {
+            IDYES => Ok(Self::Yes),
+            IDNO => Ok(Self::No),
+            IDCANCEL => Ok(Self::Cancel),
+            _ => Err(UnknownResult(value)),
+        }
+    }
+}
+
+/// Unknown codes are mapped to [`Cancel`](YesNoCancel::Cancel). Use [`try_from_code`](Self::try_from_code) to reject them.
 impl From<MESSAGEBOX_RESULT> for YesNoCancel {
     fn from(value: MESSAGEBOX_RESULT) -> Self {
         match value {
@@ -24,8 +54,49 @@ impl From<MESSAGEBOX_RESULT> for YesNoCancel {
     }
 }
 
+/// Compares the option with the raw value returned by `MessageBoxW`.
+///
+/// ```
+/// use win_msgbox::YesNoCancel;
+/// use windows_sys::Win32::UI::WindowsAndMessaging::{IDCANCEL, IDYES};
+///
+/// assert!(YesNoCancel::Cancel == IDCANCEL);
+/// assert!(YesNoCancel::Cancel != IDYES);
+/// ```
+impl PartialEq<MESSAGEBOX_RESULT> for YesNoCancel {
+    fn eq(&self, other: &MESSAGEBOX_RESULT) -> bool {
+        let code = match self {
+            Self::Yes => IDYES,
+            Self::No => IDNO,
+            Self::Cancel => IDCANCEL,
+        };
+        *other == code
+    }
+}
+
 impl Options for YesNoCancel {
-    fn flags() -> MESSAGEBOX_STYLE {
-        MB_YESNOCANCEL
+    const FLAGS: MESSAGEBOX_STYLE = MB_YESNOCANCEL;
+    const CAPTIONS: &'static [&'static str] = &["Yes", "No", "Cancel"];
+
+    fn safe_button() -> DefaultButton {
+        // Cancel
+        DefaultButton::DefaultButton3
+    }
+}
+
+/// [`Yes`](YesNoCancel::Yes) is confirmed.
+impl Confirmable for YesNoCancel {
+    fn is_confirmed(&self) -> bool {
+        *self == Self::Yes
+    }
+}
+
+impl std::fmt::Display for YesNoCancel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Yes => "Yes",
+            Self::No => "No",
+            Self::Cancel => "Cancel",
+        })
     }
 }
@@ -0,0 +1,23 @@
+use proptest::prelude::*;
+use win_msgbox::{
+    AbortRetryIgnore, CancelTryAgainContinue, Okay, OkayCancel, RetryCancel, YesNo, YesNoCancel,
+};
+
+proptest! {
+    #[test]
+    fn parse_result_code_accepts_any_code(code: i32) {
+        win_msgbox::parse_result_code::<AbortRetryIgnore>(code);
+        win_msgbox::parse_result_code::<CancelTryAgainContinue>(code);
+        win_msgbox::parse_result_code::<Okay>(code);
+        win_msgbox::parse_result_code::<OkayCancel>(code);
+        win_msgbox::parse_result_code::<RetryCancel>(code);
+        win_msgbox::parse_result_code::<YesNo>(code);
+        win_msgbox::parse_result_code::<YesNoCancel>(code);
+    }
+
+    #[test]
+    fn classify_names_known_codes(code: i32) {
+        let name = win_msgbox::classify(code);
+        prop_assert!(name == "UNKNOWN" || name.starts_with("ID"));
+    }
+}